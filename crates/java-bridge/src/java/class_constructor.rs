@@ -1,3 +1,5 @@
+use crate::java::class_method::resolve_type_arguments;
+use crate::node::helpers::functional_interfaces::resolve_functional_interfaces;
 use crate::node::util::helpers::ResultType;
 use java_rs::java_env::JavaEnv;
 use java_rs::java_type::JavaType;
@@ -7,7 +9,7 @@ use java_rs::objects::array::JavaObjectArray;
 use java_rs::objects::class::{GlobalJavaClass, JavaClass};
 use java_rs::objects::constructor::{GlobalJavaConstructor, JavaConstructor};
 use java_rs::objects::java_object::JavaObject;
-use java_rs::objects::object::GlobalJavaObject;
+use java_rs::objects::object::{GlobalJavaObject, LocalJavaObject};
 use java_rs::util::conversion::{get_constructor_from_signature, parameter_to_type};
 use std::fmt::Display;
 
@@ -84,6 +86,13 @@ impl ClassConstructor {
             parameter_types.push(parameter);
         }
 
+        let parameter_types = resolve_type_arguments(
+            env,
+            &LocalJavaObject::from(&constructor, env),
+            parameter_types,
+        )?;
+        resolve_functional_interfaces(env, &parameter_types)?;
+
         Ok(Self {
             vm,
             parameter_types: parameter_types.clone(),
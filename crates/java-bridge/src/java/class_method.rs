@@ -1,3 +1,5 @@
+use crate::node::helpers::collections::CollectionKind;
+use crate::node::helpers::functional_interfaces::resolve_functional_interfaces;
 use java_rs::java_call_result::JavaCallResult;
 use java_rs::java_env::JavaEnv;
 use java_rs::java_type::{JavaType, Type};
@@ -16,7 +18,8 @@ use java_rs::objects::method::{
 use java_rs::objects::object::{GlobalJavaObject, LocalJavaObject};
 use java_rs::objects::string::JavaString;
 use java_rs::util::conversion::{
-    get_method_from_signature, get_method_name, get_method_parameters, get_method_return_type,
+    get_generic_parameter_types, get_method_from_signature, get_method_name, get_method_parameters,
+    get_method_return_type,
 };
 use java_rs::util::helpers::{method_is_public, ResultType};
 use std::collections::HashMap;
@@ -115,6 +118,24 @@ impl ClassMethod {
         &self.parameter_types
     }
 
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Get the parameter signature of this method,
+    /// e.g. `(int,java.lang.String)`. This is used as
+    /// the key for selecting a specific overload.
+    pub fn parameter_signature(&self) -> String {
+        format!(
+            "({})",
+            self.parameter_types
+                .iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        )
+    }
+
     fn from_method(
         vm: JavaVM,
         env: &JavaEnv,
@@ -122,7 +143,9 @@ impl ClassMethod {
         class_name: String,
         is_static: bool,
     ) -> ResultType<Self> {
-        let parameter_types = get_method_parameters(env, &method)?;
+        let parameter_types =
+            resolve_type_arguments(env, &method, get_method_parameters(env, &method)?)?;
+        resolve_functional_interfaces(env, &parameter_types)?;
         let return_type = get_method_return_type(env, &method)?;
         let name = get_method_name(env, &method)?;
 
@@ -292,6 +315,41 @@ impl ClassMethod {
     }
 }
 
+/// Add the generic type arguments to all parameters which may be
+/// converted from javascript collections. The type arguments of any
+/// other parameters are not resolved, as this requires reflection.
+pub fn resolve_type_arguments(
+    env: &JavaEnv,
+    executable: &LocalJavaObject,
+    parameter_types: Vec<JavaType>,
+) -> ResultType<Vec<JavaType>> {
+    if !parameter_types
+        .iter()
+        .any(|t| CollectionKind::from_type(t).is_some())
+    {
+        return Ok(parameter_types);
+    }
+
+    // The generic parameter types of constructors of
+    // inner classes may not contain the implicit parameters
+    let generic_types = get_generic_parameter_types(env, executable)?;
+    if generic_types.len() != parameter_types.len() {
+        return Ok(parameter_types);
+    }
+
+    Ok(parameter_types
+        .into_iter()
+        .zip(generic_types)
+        .map(|(t, generic)| {
+            if CollectionKind::from_type(&t).is_some() {
+                t.with_type_arguments(generic.type_arguments().to_vec())
+            } else {
+                t
+            }
+        })
+        .collect())
+}
+
 impl Display for ClassMethod {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
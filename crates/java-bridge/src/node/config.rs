@@ -9,12 +9,42 @@ lazy_static! {
     static ref CONFIG: Mutex<Config> = Mutex::new(Config::default());
 }
 
+/// How java `long` values should be converted to javascript values.
+///
+/// @since 2.9.0
+#[napi(string_enum = "lowercase")]
+#[derive(Debug, Eq, PartialEq)]
+pub enum LongMode {
+    /// Always convert `long` values to `BigInt`s.
+    BigInt,
+    /// Always convert `long` values to `number`s.
+    /// Values larger than `Number.MAX_SAFE_INTEGER` will lose precision.
+    Number,
+    /// Convert `long` values to `number`s if they can be
+    /// represented safely, otherwise convert them to `BigInt`s.
+    Auto,
+}
+
+/// How `java.math.BigDecimal` values should be converted to javascript values.
+///
+/// @since 2.9.0
+#[napi(string_enum = "lowercase")]
+#[derive(Debug, Eq, PartialEq)]
+pub enum BigDecimalMode {
+    /// Convert `BigDecimal` values to decimal strings.
+    String,
+    /// Convert `BigDecimal` values to objects in the form `{ unscaled, scale }`
+    /// where `unscaled` is a `BigInt` and `scale` is a `number`.
+    Object,
+}
+
 /// Configuration for the Java class proxy.
 ///
 /// @since 2.4.0
 #[napi(object)]
 pub struct ClassConfiguration {
-    /// If true, the event loop will be run when an interface proxy is active.
+    /// If true, interface proxies called by other java threads
+    /// during synchronous calls are called by the waiting node thread.
     /// If not specified, the value from the global configuration will be used.
     pub run_event_loop_when_interface_proxy_is_active: Option<bool>,
     /// If true, the custom inspect method will be used to display the object in the console.
@@ -40,6 +70,78 @@ pub struct ClassConfiguration {
     ///
     /// @since 2.6.0
     pub async_java_exception_objects: Option<bool>,
+    /// How java `long` values (including `java.lang.Long` and `long[]`)
+    /// should be converted to javascript values.
+    /// If not specified, the value from the global configuration will be used.
+    ///
+    /// @since 2.9.0
+    #[napi(ts_type = "'bigint' | 'number' | 'auto'")]
+    pub long_mode: Option<LongMode>,
+    /// If true, javascript numbers which cannot be converted to
+    /// the required java type without losing information will
+    /// be rejected instead of being truncated.
+    /// If not specified, the value from the global configuration will be used.
+    ///
+    /// @since 2.9.0
+    pub strict_numbers: Option<bool>,
+    /// If true, `java.util.List`, `java.util.Set` and `java.util.Map`
+    /// values will be converted to javascript arrays, `Set`s and `Map`s.
+    /// If not specified, the value from the global configuration will be used.
+    ///
+    /// @since 2.9.0
+    pub convert_collections: Option<bool>,
+    /// If true, `java.util.concurrent.CompletionStage` and
+    /// `java.util.concurrent.Future` values will be converted
+    /// to javascript promises.
+    /// If not specified, the value from the global configuration will be used.
+    ///
+    /// @since 2.9.0
+    pub convert_futures: Option<bool>,
+    /// If true, `java.util.Optional`, `java.util.OptionalInt`,
+    /// `java.util.OptionalLong` and `java.util.OptionalDouble`
+    /// values will be converted to their contained value or `null`.
+    /// If not specified, the value from the global configuration will be used.
+    ///
+    /// @since 2.9.0
+    pub unwrap_optionals: Option<bool>,
+    /// If true, `java.util.Date` and `java.time.Instant`
+    /// values will be converted to javascript `Date`s.
+    /// If not specified, the value from the global configuration will be used.
+    ///
+    /// @since 2.9.0
+    pub convert_dates: Option<bool>,
+    /// The time zone id used when converting javascript `Date`s
+    /// to `java.time.LocalDateTime` and `java.time.ZonedDateTime`.
+    /// If not specified, the value from the global configuration will be used.
+    ///
+    /// @since 2.9.0
+    pub time_zone: Option<String>,
+    /// If true, `java.math.BigInteger` values will be converted to
+    /// `BigInt`s and `java.math.BigDecimal` values will be converted
+    /// to javascript values depending on the `bigDecimalMode`.
+    /// If not specified, the value from the global configuration will be used.
+    ///
+    /// @since 2.9.0
+    pub convert_big_numbers: Option<bool>,
+    /// How `java.math.BigDecimal` values should be converted
+    /// to javascript values if `convertBigNumbers` is set.
+    /// If not specified, the value from the global configuration will be used.
+    ///
+    /// @since 2.9.0
+    #[napi(ts_type = "'string' | 'object'")]
+    pub big_decimal_mode: Option<BigDecimalMode>,
+    /// If true, primitive java arrays except `boolean[]`
+    /// will be converted to javascript typed arrays.
+    /// If not specified, the value from the global configuration will be used.
+    ///
+    /// @since 2.9.0
+    pub typed_arrays: Option<bool>,
+    /// If true, the contents of javascript arrays and typed arrays
+    /// passed as java arrays will be updated after the call.
+    /// If not specified, the value from the global configuration will be used.
+    ///
+    /// @since 2.9.0
+    pub write_back_arrays: Option<bool>,
 }
 
 impl TryFrom<ClassConfiguration> for Config {
@@ -72,6 +174,18 @@ impl TryFrom<ClassConfiguration> for Config {
             async_java_exception_objects: value
                 .async_java_exception_objects
                 .or(config.async_java_exception_objects),
+            long_mode: value.long_mode.or(config.long_mode),
+            strict_numbers: value.strict_numbers.or(config.strict_numbers),
+            convert_collections: value.convert_collections.or(config.convert_collections),
+            convert_futures: value.convert_futures.or(config.convert_futures),
+            unwrap_optionals: value.unwrap_optionals.or(config.unwrap_optionals),
+            convert_dates: value.convert_dates.or(config.convert_dates),
+            time_zone: value.time_zone.or(config.time_zone.clone()),
+            convert_big_numbers: value.convert_big_numbers.or(config.convert_big_numbers),
+            big_decimal_mode: value.big_decimal_mode.or(config.big_decimal_mode),
+            typed_arrays: value.typed_arrays.or(config.typed_arrays),
+            write_back_arrays: value.write_back_arrays.or(config.write_back_arrays),
+            capture_proxy_stacks: config.capture_proxy_stacks,
         })
     }
 }
@@ -82,7 +196,8 @@ impl TryFrom<ClassConfiguration> for Config {
 #[napi(object)]
 #[derive(SmartDefault, Clone, Eq, PartialEq)]
 pub struct Config {
-    /// If true, the event loop will be run when an interface proxy is active.
+    /// If true, interface proxies called by other java threads
+    /// during synchronous calls are called by the waiting node thread.
     ///
     /// @since 2.2.3
     #[default(false)]
@@ -117,6 +232,125 @@ pub struct Config {
     /// @since 2.6.0
     #[default(None)]
     pub async_java_exception_objects: Option<bool>,
+    /// How java `long` values should be converted to javascript values.
+    /// `'bigint'` always returns `BigInt`s, `'number'` always returns
+    /// `number`s and `'auto'` returns a `number` if the value can be
+    /// represented safely and a `BigInt` otherwise.
+    /// This applies to `long`, `java.lang.Long` and `long[]` values
+    /// returned from methods and fields.
+    /// If not set, `long` and `java.lang.Long` values are converted to
+    /// `BigInt`s and the values of `long[]` are converted to `number`s.
+    ///
+    /// @since 2.9.0
+    #[default(None)]
+    #[napi(ts_type = "'bigint' | 'number' | 'auto'")]
+    pub long_mode: Option<LongMode>,
+    /// If true, javascript numbers which cannot be converted to
+    /// the required java type without losing information will
+    /// be rejected instead of being truncated. This includes
+    /// non-integral values for integral types, values out of range
+    /// for `byte`, `short` and `int` and values larger than
+    /// `Number.MAX_SAFE_INTEGER` for `long`.
+    /// Default is false.
+    ///
+    /// @since 2.9.0
+    #[default(None)]
+    pub strict_numbers: Option<bool>,
+    /// If true, `java.util.List`, `java.util.Set` and `java.util.Map`
+    /// values returned from java will be converted to javascript
+    /// arrays, `Set`s and `Map`s, converting their elements recursively.
+    /// Otherwise, these values will be returned as java objects.
+    /// Default is false.
+    ///
+    /// @since 2.9.0
+    #[default(None)]
+    pub convert_collections: Option<bool>,
+    /// If true, `java.util.concurrent.CompletionStage` and
+    /// `java.util.concurrent.Future` values returned from java
+    /// will be converted to javascript promises which are
+    /// resolved once the future completes.
+    /// Otherwise, these values will be returned as java objects.
+    /// Default is false.
+    ///
+    /// @since 2.9.0
+    #[default(None)]
+    pub convert_futures: Option<bool>,
+    /// If true, `java.util.Optional`, `java.util.OptionalInt`,
+    /// `java.util.OptionalLong` and `java.util.OptionalDouble`
+    /// values returned from java will be converted to the
+    /// contained value or `null` if the optional is empty.
+    /// Otherwise, these values will be returned as java objects.
+    /// Default is false.
+    ///
+    /// @since 2.9.0
+    #[default(None)]
+    pub unwrap_optionals: Option<bool>,
+    /// If true, `java.util.Date` and `java.time.Instant`
+    /// values returned from java will be converted to
+    /// javascript `Date`s.
+    /// Otherwise, these values will be returned as java objects.
+    /// Default is false.
+    ///
+    /// @since 2.9.0
+    #[default(None)]
+    pub convert_dates: Option<bool>,
+    /// The time zone id used when converting javascript `Date`s
+    /// to `java.time.LocalDateTime` and `java.time.ZonedDateTime`,
+    /// for example `UTC` or `Europe/Berlin`.
+    /// Default is the system default time zone of the jvm.
+    ///
+    /// @since 2.9.0
+    #[default(None)]
+    pub time_zone: Option<String>,
+    /// If true, `java.math.BigInteger` values returned from java
+    /// will be converted to `BigInt`s and `java.math.BigDecimal`
+    /// values will be converted depending on the `bigDecimalMode`.
+    /// Otherwise, these values will be returned as java objects.
+    /// Default is false.
+    ///
+    /// @since 2.9.0
+    #[default(None)]
+    pub convert_big_numbers: Option<bool>,
+    /// How `java.math.BigDecimal` values returned from java should
+    /// be converted if `convertBigNumbers` is set. `'string'` returns
+    /// decimal strings and `'object'` returns objects in the form
+    /// `{ unscaled, scale }`.
+    /// Default is `'string'`.
+    ///
+    /// @since 2.9.0
+    #[default(None)]
+    #[napi(ts_type = "'string' | 'object'")]
+    pub big_decimal_mode: Option<BigDecimalMode>,
+    /// If true, `int[]`, `long[]`, `short[]`, `double[]`, `float[]`
+    /// and `char[]` values returned from java will be converted to
+    /// `Int32Array`s, `BigInt64Array`s, `Int16Array`s, `Float64Array`s,
+    /// `Float32Array`s and `Uint16Array`s.
+    /// Otherwise, these values will be converted to javascript arrays.
+    /// `byte[]` values are always converted to `Buffer`s.
+    /// Default is false.
+    ///
+    /// @since 2.9.0
+    #[default(None)]
+    pub typed_arrays: Option<bool>,
+    /// If true, the contents of javascript arrays and typed arrays
+    /// passed to java methods or constructors as java arrays will
+    /// be updated with the contents of the java arrays after the call,
+    /// making changes made by java visible to javascript.
+    /// Otherwise, only arrays wrapped using `out` will be updated.
+    /// Default is false.
+    ///
+    /// @since 2.9.0
+    #[default(None)]
+    pub write_back_arrays: Option<bool>,
+    /// If true, the javascript stack trace at the creation of
+    /// each interface proxy is recorded and returned by
+    /// `proxies.list`, which helps finding leaked proxies.
+    /// This does not apply to proxies created before setting it.
+    /// Default is false.
+    ///
+    /// @since 2.9.0
+    #[default(None)]
+    pub capture_proxy_stacks: Option<bool>,
 }
 
 impl Config {
@@ -1,6 +1,9 @@
 use crate::java::class_constructor::ClassConstructor;
 use crate::java::class_method::ClassMethod;
 use crate::node::extensions::java_type_ext::JsTypeEq;
+use crate::node::helpers::napi_error::MapToNapiError;
+use crate::node::java_out_argument::get_argument;
+use crate::node::java_type_hint::JavaTypeHint;
 use java_rs::java_type::{JavaType, Type};
 use napi::{CallContext, Env, JsUnknown};
 
@@ -14,6 +17,12 @@ fn argument_matches(
     env: &Env,
     allow_objects: bool,
 ) -> napi::Result<bool> {
+    // Type hints should only match their exact type, unless
+    // no overload with the exact type exists
+    if let Some(hint) = JavaTypeHint::from_js(env, &value)? {
+        return hint.matches(arg, allow_objects).map_napi_err(Some(*env));
+    }
+
     Ok(arg.js_equals(value, env)? || (allow_objects && arg.type_enum() == Type::LangObject))
 }
 
@@ -27,7 +36,7 @@ fn arguments_match(
     }
 
     for (i, param) in parameter_types.iter().enumerate() {
-        if !argument_matches(param, ctx.get(i)?, ctx.env, allow_objects)? {
+        if !argument_matches(param, get_argument(ctx, i)?, ctx.env, allow_objects)? {
             return Ok(false);
         }
     }
@@ -1,5 +1,12 @@
 use crate::node::class_cache::ClassCache;
+use crate::node::config::{BigDecimalMode, Config, LongMode};
+use crate::node::helpers::big_numbers::big_number_to_napi_value;
+use crate::node::helpers::collections::new_from_array;
+use crate::node::helpers::dates::java_date_to_millis;
+use crate::node::helpers::optionals::OptionalKind;
+use crate::node::helpers::typed_arrays::{java_array_to_typed_array, typed_array_type};
 use crate::node::java_class_instance::JavaClassInstance;
+use crate::node::java_future::{future_to_promise, is_future};
 use crate::node::util::helpers::ResultType;
 use app_state::{stateful, AppStateTrait, MutAppState};
 use java_rs::java_call_result::JavaCallResult;
@@ -9,6 +16,7 @@ use java_rs::objects::array::{
     JavaArray, JavaBooleanArray, JavaByteArray, JavaCharArray, JavaDoubleArray, JavaFloatArray,
     JavaIntArray, JavaLongArray, JavaObjectArray, JavaShortArray,
 };
+use java_rs::objects::class::JavaClass;
 use java_rs::objects::java_object::JavaObject;
 use java_rs::objects::object::{GlobalJavaObject, LocalJavaObject};
 use java_rs::traits::IsInstanceOf;
@@ -16,8 +24,25 @@ use napi::{Env, JsUnknown};
 use std::borrow::Borrow;
 use std::sync::{Arc, Mutex};
 
+/// The largest integer that can be safely represented by a javascript number.
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+fn long_to_napi_value(value: i64, env: &Env, mode: LongMode) -> ResultType<JsUnknown> {
+    let as_number = match mode {
+        LongMode::BigInt => false,
+        LongMode::Number => true,
+        LongMode::Auto => value.unsigned_abs() <= MAX_SAFE_INTEGER,
+    };
+
+    Ok(if as_number {
+        env.create_int64(value)?.into_unknown()
+    } else {
+        env.create_bigint_from_i64(value)?.into_unknown()?
+    })
+}
+
 pub trait ToNapiValue {
-    fn to_napi_value(&self, j_env: &JavaEnv, env: &Env) -> ResultType<JsUnknown>;
+    fn to_napi_value(&self, j_env: &JavaEnv, env: &Env, config: &Config) -> ResultType<JsUnknown>;
     fn resolve_object_type(
         &self,
         object: &GlobalJavaObject,
@@ -32,6 +57,7 @@ pub trait ToNapiValue {
         env: &Env,
         signature: &JavaType,
         objects: bool,
+        config: &Config,
     ) -> ResultType<JsUnknown>;
     fn array_to_napi_value(
         &self,
@@ -39,11 +65,26 @@ pub trait ToNapiValue {
         j_env: &JavaEnv,
         env: &Env,
         signature: Arc<Mutex<JavaType>>,
+        config: &Config,
     ) -> ResultType<JsUnknown>;
+    fn collection_to_napi_value(
+        &self,
+        object: &GlobalJavaObject,
+        j_env: &JavaEnv,
+        env: &Env,
+        config: &Config,
+    ) -> ResultType<Option<JsUnknown>>;
+    fn optional_to_napi_value(
+        &self,
+        object: &GlobalJavaObject,
+        j_env: &JavaEnv,
+        env: &Env,
+        config: &Config,
+    ) -> ResultType<Option<JsUnknown>>;
 }
 
 impl ToNapiValue for JavaCallResult {
-    fn to_napi_value(&self, j_env: &JavaEnv, env: &Env) -> ResultType<JsUnknown> {
+    fn to_napi_value(&self, j_env: &JavaEnv, env: &Env, config: &Config) -> ResultType<JsUnknown> {
         Ok(match self {
             JavaCallResult::Void => env.get_undefined()?.into_unknown(),
             JavaCallResult::Null => env.get_null()?.into_unknown(),
@@ -52,11 +93,13 @@ impl ToNapiValue for JavaCallResult {
             JavaCallResult::Character(c) => env.create_string_utf16(&[*c])?.into_unknown(),
             JavaCallResult::Short(s) => env.create_int32(*s as i32)?.into_unknown(),
             JavaCallResult::Integer(i) => env.create_int32(*i)?.into_unknown(),
-            JavaCallResult::Long(l) => env.create_bigint_from_i64(*l)?.into_unknown()?,
+            JavaCallResult::Long(l) => {
+                long_to_napi_value(*l, env, config.long_mode.unwrap_or(LongMode::BigInt))?
+            }
             JavaCallResult::Float(f) => env.create_double(*f as f64)?.into_unknown(),
             JavaCallResult::Double(d) => env.create_double(*d)?.into_unknown(),
             JavaCallResult::Object { object, signature } => {
-                self.object_to_napi_value(object, j_env, env, signature, true)?
+                self.object_to_napi_value(object, j_env, env, signature, true, config)?
             }
         })
     }
@@ -126,14 +169,17 @@ impl ToNapiValue for JavaCallResult {
         env: &Env,
         signature: &JavaType,
         objects: bool,
+        config: &Config,
         cache: MutAppState<ClassCache>,
     ) -> ResultType<JsUnknown> {
         let obj = LocalJavaObject::from(object, j_env);
         let res = match signature.type_enum() {
             Type::LangInteger => env.create_int32(j_env.object_to_int(&obj)?)?.into_unknown(),
-            Type::LangLong => env
-                .create_bigint_from_i64(j_env.object_to_long(&obj)?)?
-                .into_unknown()?,
+            Type::LangLong => long_to_napi_value(
+                j_env.object_to_long(&obj)?,
+                env,
+                config.long_mode.unwrap_or(LongMode::BigInt),
+            )?,
             Type::LangShort => env
                 .create_int32(j_env.object_to_short(&obj)? as i32)?
                 .into_unknown(),
@@ -161,6 +207,7 @@ impl ToNapiValue for JavaCallResult {
                 j_env,
                 env,
                 signature.inner().ok_or("No inner type provided")?,
+                config,
             )?,
             Type::LangObject | Type::Object => {
                 if objects {
@@ -170,7 +217,46 @@ impl ToNapiValue for JavaCallResult {
                         env,
                         &self.resolve_object_type(object, j_env, signature, true)?,
                         false,
+                        config,
                     )?
+                } else if let Some(value) = config
+                    .unwrap_optionals
+                    .unwrap_or_default()
+                    .then(|| self.optional_to_napi_value(object, j_env, env, config))
+                    .transpose()?
+                    .flatten()
+                {
+                    value
+                } else if let Some(collection) = config
+                    .convert_collections
+                    .unwrap_or_default()
+                    .then(|| self.collection_to_napi_value(object, j_env, env, config))
+                    .transpose()?
+                    .flatten()
+                {
+                    collection
+                } else if let Some(millis) = config
+                    .convert_dates
+                    .unwrap_or_default()
+                    .then(|| java_date_to_millis(object, j_env))
+                    .transpose()?
+                    .flatten()
+                {
+                    env.create_date(millis as f64)?.into_unknown()
+                } else if let Some(value) = config
+                    .convert_big_numbers
+                    .unwrap_or_default()
+                    .then(|| {
+                        let mode = config.big_decimal_mode.unwrap_or(BigDecimalMode::String);
+                        big_number_to_napi_value(object, j_env, env, mode)
+                    })
+                    .transpose()?
+                    .flatten()
+                {
+                    value
+                } else if config.convert_futures.unwrap_or_default() && is_future(object)? {
+                    let vm = j_env.get_java_vm()?;
+                    future_to_promise(env, &vm, object.clone(), config, None)?.into_unknown()
                 } else {
                     let vm = j_env.get_java_vm()?;
                     let proxy =
@@ -195,12 +281,19 @@ impl ToNapiValue for JavaCallResult {
         j_env: &JavaEnv,
         env: &Env,
         signature: Arc<Mutex<JavaType>>,
+        config: &Config,
     ) -> ResultType<JsUnknown> {
         let obj = LocalJavaObject::from(object, j_env);
         let arr = JavaArray::from(obj);
+        let sig = signature.lock().unwrap();
+
+        if config.typed_arrays.unwrap_or_default() && !sig.is_byte() {
+            if let Some(typed_array_type) = typed_array_type(&sig) {
+                return java_array_to_typed_array(env, arr, &sig, typed_array_type);
+            }
+        }
 
         let mut res = env.create_array(arr.len()? as u32)?;
-        let sig = signature.lock().unwrap();
 
         match sig.type_enum() {
             Type::Integer => {
@@ -210,9 +303,11 @@ impl ToNapiValue for JavaCallResult {
                 }
             }
             Type::Long => {
+                // Long arrays are converted to number arrays by default
+                let mode = config.long_mode.unwrap_or(LongMode::Number);
                 let data = JavaLongArray::from(arr).get_data()?;
                 for (i, item) in data.iter().enumerate() {
-                    res.set(i as u32, *item)?;
+                    res.set(i as u32, long_to_napi_value(*item, env, mode)?)?;
                 }
             }
             Type::Short => {
@@ -275,7 +370,14 @@ impl ToNapiValue for JavaCallResult {
                         i as u32,
                         if let Some(obj) = array.get(i)? {
                             let obj = GlobalJavaObject::try_from(obj)?;
-                            self.object_to_napi_value(&obj, j_env, env, sig.clone().borrow(), true)?
+                            self.object_to_napi_value(
+                                &obj,
+                                j_env,
+                                env,
+                                sig.clone().borrow(),
+                                true,
+                                config,
+                            )?
                         } else {
                             env.get_null()?.into_unknown()
                         },
@@ -286,4 +388,143 @@ impl ToNapiValue for JavaCallResult {
 
         Ok(res.coerce_to_object()?.into_unknown())
     }
+
+    /// Convert a `java.util.List`, `java.util.Set` or `java.util.Map` to a
+    /// javascript array, `Set` or `Map`. The contents of the collection
+    /// are copied to an array using a single call to `toArray()`.
+    /// Returns `None` if `object` is not a list, set or map.
+    fn collection_to_napi_value(
+        &self,
+        object: &GlobalJavaObject,
+        j_env: &JavaEnv,
+        env: &Env,
+        config: &Config,
+    ) -> ResultType<Option<JsUnknown>> {
+        let is_list = object.is_instance_of("java/util/List")?;
+        let is_set = !is_list && object.is_instance_of("java/util/Set")?;
+        let is_map = !is_list && !is_set && object.is_instance_of("java/util/Map")?;
+        if !is_list && !is_set && !is_map {
+            return Ok(None);
+        }
+
+        let collection_class = JavaClass::by_name("java/util/Collection", j_env)?;
+        let to_array = collection_class.get_object_method("toArray", "()[Ljava/lang/Object;")?;
+        let map_class = JavaClass::by_name("java/util/Map", j_env)?;
+        let collection = if is_map {
+            let entry_set = map_class.get_object_method("entrySet", "()Ljava/util/Set;")?;
+
+            entry_set
+                .call(JavaObject::from(object), &[])?
+                .ok_or("Map.entrySet() returned null".to_string())?
+        } else {
+            LocalJavaObject::from(object, j_env)
+        };
+
+        let array = to_array
+            .call(JavaObject::from(&collection), &[])?
+            .ok_or("Collection.toArray() returned null".to_string())?;
+
+        if !is_map {
+            let values = self.array_to_napi_value(
+                &GlobalJavaObject::try_from(array)?,
+                j_env,
+                env,
+                Arc::new(Mutex::new(JavaType::object())),
+                config,
+            )?;
+
+            return Ok(Some(if is_list {
+                values
+            } else {
+                new_from_array(env, "Set", values.coerce_to_object()?)?
+            }));
+        }
+
+        let entry_class = JavaClass::by_name("java/util/Map$Entry", j_env)?;
+        let get_key = entry_class.get_object_method("getKey", "()Ljava/lang/Object;")?;
+        let get_value = entry_class.get_object_method("getValue", "()Ljava/lang/Object;")?;
+        let to_napi = |value: Option<LocalJavaObject>| -> ResultType<JsUnknown> {
+            match value {
+                Some(value) => self.object_to_napi_value(
+                    &GlobalJavaObject::try_from(value)?,
+                    j_env,
+                    env,
+                    &JavaType::object(),
+                    true,
+                    config,
+                ),
+                None => Ok(env.get_null()?.into_unknown()),
+            }
+        };
+
+        let entries = JavaObjectArray::from(array);
+        let mut res = env.create_array_with_length(entries.len()? as usize)?;
+        for i in 0..entries.len()? {
+            let entry = entries
+                .get(i)?
+                .ok_or("Map.entrySet() contained a null value".to_string())?;
+
+            let mut pair = env.create_array_with_length(2)?;
+            pair.set_element(0, to_napi(get_key.call(JavaObject::from(&entry), &[])?)?)?;
+            pair.set_element(1, to_napi(get_value.call(JavaObject::from(&entry), &[])?)?)?;
+            res.set_element(i as u32, pair)?;
+        }
+
+        Ok(Some(new_from_array(env, "Map", res)?))
+    }
+
+    /// Convert a `java.util.Optional`, `java.util.OptionalInt`,
+    /// `java.util.OptionalLong` or `java.util.OptionalDouble` to
+    /// the contained value or `null` if the optional is empty.
+    /// Returns `None` if `object` is not an optional.
+    fn optional_to_napi_value(
+        &self,
+        object: &GlobalJavaObject,
+        j_env: &JavaEnv,
+        env: &Env,
+        config: &Config,
+    ) -> ResultType<Option<JsUnknown>> {
+        let Some(kind) = OptionalKind::from_object(object)? else {
+            return Ok(None);
+        };
+
+        let class = JavaClass::by_name(kind.class_name(), j_env)?;
+        let is_present = class
+            .get_boolean_method("isPresent", "()Z")?
+            .call(JavaObject::from(object), &[])?;
+        if !is_present {
+            return Ok(Some(env.get_null()?.into_unknown()));
+        }
+
+        let value = match kind {
+            OptionalKind::Object => class
+                .get_object_method("get", "()Ljava/lang/Object;")?
+                .call(JavaObject::from(object), &[])?
+                .map(|value| -> ResultType<JavaCallResult> {
+                    Ok(JavaCallResult::Object {
+                        object: GlobalJavaObject::try_from(value)?,
+                        signature: JavaType::object(),
+                    })
+                })
+                .transpose()?
+                .unwrap_or(JavaCallResult::Null),
+            OptionalKind::Int => JavaCallResult::Integer(
+                class
+                    .get_int_method("getAsInt", "()I")?
+                    .call(JavaObject::from(object), &[])?,
+            ),
+            OptionalKind::Long => JavaCallResult::Long(
+                class
+                    .get_long_method("getAsLong", "()J")?
+                    .call(JavaObject::from(object), &[])?,
+            ),
+            OptionalKind::Double => JavaCallResult::Double(
+                class
+                    .get_double_method("getAsDouble", "()D")?
+                    .call(JavaObject::from(object), &[])?,
+            ),
+        };
+
+        value.to_napi_value(j_env, env, config).map(Some)
+    }
 }
@@ -1,10 +1,23 @@
+use crate::node::config::Config;
+use crate::node::helpers::big_numbers::BigNumberKind;
+use crate::node::helpers::collections::{collection_to_array, is_instance_of, CollectionKind};
+use crate::node::helpers::dates::{date_to_millis, DateKind};
+use crate::node::helpers::functional_interfaces::get_functional_method;
 use crate::node::helpers::js_to_java_object::{JsIntoJavaObject, JsToJavaClass};
 use crate::node::helpers::napi_error::{MapToNapiError, NapiError};
+use crate::node::helpers::optionals::OptionalKind;
+use crate::node::helpers::typed_arrays::{
+    accepts_typed_array, get_typed_array_type, typed_array_to_java,
+};
+use crate::node::interface_proxy::function_proxy::is_plain_function;
 use crate::node::interface_proxy::java_interface_proxy::JavaInterfaceProxy;
 use crate::node::java_class_instance::OBJECT_PROPERTY;
+use crate::node::java_future::{accepts_promise, is_thenable, promise_to_future};
+use crate::node::java_type_hint::JavaTypeHint;
 use java_rs::java_call_result::JavaCallResult;
 use java_rs::java_env::JavaEnv;
 use java_rs::java_type::{JavaType, Type};
+use java_rs::objects::args::AsJavaArg;
 use java_rs::objects::array::{
     JavaBooleanArray, JavaByteArray, JavaCharArray, JavaDoubleArray, JavaFloatArray, JavaIntArray,
     JavaLongArray, JavaObjectArray, JavaShortArray,
@@ -13,6 +26,7 @@ use java_rs::objects::class::JavaClass;
 use java_rs::objects::java_object::JavaObject;
 use java_rs::objects::object::{GlobalJavaObject, LocalJavaObject};
 use java_rs::objects::string::JavaString;
+use java_rs::objects::value::JavaNull;
 use java_rs::traits::GetSignature;
 use java_rs::util::helpers::ResultType;
 use napi::{
@@ -39,17 +53,223 @@ fn is_integer(env: &Env, value: &JsNumber) -> ResultType<bool> {
         .get_value()?)
 }
 
+const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+/// Check if `value` can be converted to the integral type `java_type`
+/// without losing information. This is only checked if strict
+/// numbers are enabled, otherwise values are truncated as usual.
+fn check_number(value: f64, java_type: Type, config: &Config) -> ResultType<()> {
+    if !config.strict_numbers.unwrap_or_default() {
+        return Ok(());
+    }
+
+    check_integral(value, java_type)
+}
+
+/// Check if `value` can be converted to the integral type
+/// `java_type` without losing information, regardless of the
+/// config. Does nothing if `java_type` is not an integral type.
+pub fn check_integral(value: f64, java_type: Type) -> ResultType<()> {
+    let (name, min, max) = match java_type {
+        Type::Byte | Type::LangByte => ("byte", i8::MIN as f64, i8::MAX as f64),
+        Type::Short | Type::LangShort => ("short", i16::MIN as f64, i16::MAX as f64),
+        Type::Integer | Type::LangInteger => ("int", i32::MIN as f64, i32::MAX as f64),
+        Type::Long | Type::LangLong => ("long", -MAX_SAFE_INTEGER, MAX_SAFE_INTEGER),
+        _ => return Ok(()),
+    };
+
+    if !value.is_finite() || value.fract() != 0.0 {
+        Err(format!(
+            "Cannot convert {} to '{}': the value is not an integer",
+            value, name
+        )
+        .into())
+    } else if name == "long" && !(min..=max).contains(&value) {
+        Err(format!(
+            "Cannot convert {} to 'long': the value is not a safe integer, use a BigInt instead",
+            value
+        )
+        .into())
+    } else if !(min..=max).contains(&value) {
+        Err(format!(
+            "Cannot convert {} to '{}': the value must be between {} and {}",
+            value, name, min, max
+        )
+        .into())
+    } else {
+        Ok(())
+    }
+}
+
+fn checked_number(number: JsNumber, java_type: Type, config: &Config) -> ResultType<JsNumber> {
+    check_number(number.get_double()?, java_type, config)?;
+
+    Ok(number)
+}
+
+fn get_long(value: JsUnknown, config: &Config) -> ResultType<i64> {
+    if value.get_type()? == ValueType::BigInt {
+        let (val, lossless) = unsafe { value.cast::<JsBigInt>() }.get_i64()?;
+        if !lossless && config.strict_numbers.unwrap_or_default() {
+            return Err("Cannot convert BigInt to 'long': the value is out of range".into());
+        }
+
+        Ok(val)
+    } else {
+        Ok(checked_number(value.coerce_to_number()?, Type::Long, config)?.get_int64()?)
+    }
+}
+
+/// Convert a javascript array, `Set`, `Map` or plain object to a new
+/// java collection of the given kind. The elements are converted
+/// using the type arguments of `java_type`, if there are any.
+fn js_to_java_collection<'a>(
+    java_type: &JavaType,
+    kind: CollectionKind,
+    env: &'a JavaEnv<'a>,
+    node_env: &'a Env,
+    value: JsUnknown,
+    config: &Config,
+) -> ResultType<JavaObject<'a>> {
+    let type_argument = |i: usize| {
+        java_type
+            .type_arguments()
+            .get(i)
+            .cloned()
+            .unwrap_or_else(JavaType::object)
+    };
+
+    let class = JavaClass::by_name(kind.implementation(), env)?;
+    let instance = class.get_constructor("()V")?.new_instance(env, &[])?;
+    let values = collection_to_array(node_env, &value)?;
+    let length = values.get_array_length()?;
+
+    if kind == CollectionKind::Map {
+        let put = class.get_object_method(
+            "put",
+            "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+        )?;
+        let (key_type, value_type) = (type_argument(0), type_argument(1));
+
+        for i in 0..length {
+            let entry: JsObject = values.get_element(i)?;
+            let key =
+                key_type.convert_to_java_object(env, node_env, entry.get_element(0)?, config)?;
+            let value =
+                value_type.convert_to_java_object(env, node_env, entry.get_element(1)?, config)?;
+
+            let null = JavaNull;
+            put.call(
+                JavaObject::from(&instance),
+                &[
+                    key.as_ref().map(|k| k.as_arg()).unwrap_or(null.as_arg()),
+                    value.as_ref().map(|v| v.as_arg()).unwrap_or(null.as_arg()),
+                ],
+            )?;
+        }
+    } else {
+        let add = class.get_boolean_method("add", "(Ljava/lang/Object;)Z")?;
+        let element_type = type_argument(0);
+
+        for i in 0..length {
+            let element = element_type.convert_to_java_object(
+                env,
+                node_env,
+                values.get_element(i)?,
+                config,
+            )?;
+
+            let null = JavaNull;
+            add.call(
+                JavaObject::from(&instance),
+                &[element
+                    .as_ref()
+                    .map(|e| e.as_arg())
+                    .unwrap_or(null.as_arg())],
+            )?;
+        }
+    }
+
+    Ok(JavaObject::from(instance))
+}
+
+/// Wrap a javascript value in a new java optional of the given kind.
+/// `null` and `undefined` are converted to an empty optional, any other
+/// value is converted using the type argument of `java_type`, if there
+/// is one, and wrapped using `Optional.ofNullable` or `OptionalX.of`.
+fn js_to_java_optional<'a>(
+    java_type: &JavaType,
+    kind: OptionalKind,
+    env: &'a JavaEnv<'a>,
+    node_env: &'a Env,
+    value: JsUnknown,
+    config: &Config,
+) -> ResultType<JavaObject<'a>> {
+    let class = JavaClass::by_name(kind.class_name(), env)?;
+    let return_type = format!("L{};", kind.class_name());
+
+    let optional = match (value.get_type()?, kind.primitive_type()) {
+        (ValueType::Null | ValueType::Undefined, _) => class
+            .get_static_object_method("empty", &format!("(){}", return_type))?
+            .call(&[])?,
+        (_, None) => {
+            let element = java_type
+                .type_arguments()
+                .first()
+                .cloned()
+                .unwrap_or_else(JavaType::object)
+                .convert_to_java_object(env, node_env, value, config)?;
+
+            let null = JavaNull;
+            class
+                .get_static_object_method(
+                    "ofNullable",
+                    &format!("(Ljava/lang/Object;){}", return_type),
+                )?
+                .call(&[element
+                    .as_ref()
+                    .map(|e| e.as_arg())
+                    .unwrap_or(null.as_arg())])?
+        }
+        (_, Some(primitive)) => {
+            let primitive = JavaType::new(primitive.to_string(), false);
+            let element = primitive.convert_to_java_value(env, node_env, value, config)?;
+
+            class
+                .get_static_object_method(
+                    "of",
+                    &format!("({}){}", primitive.to_jni_type(), return_type),
+                )?
+                .call(&[element.as_arg()])?
+        }
+    }
+    .ok_or(format!("Could not create an instance of {}", java_type))?;
+
+    Ok(JavaObject::from(GlobalJavaObject::try_from(optional)?))
+}
+
 pub trait JsTypeEq {
     fn js_equals(&self, other: JsUnknown, env: &Env) -> napi::Result<bool>;
 }
 
 impl JsTypeEq for JavaType {
     fn js_equals(&self, other: JsUnknown, env: &Env) -> napi::Result<bool> {
+        if let Some(kind) = OptionalKind::from_type(self) {
+            if JavaTypeHint::from_js(env, &other)?.is_none() && kind.accepts(env, &other)? {
+                return Ok(true);
+            }
+        } else if let Some(kind) = BigNumberKind::from_type(self) {
+            if JavaTypeHint::from_js(env, &other)?.is_none() && kind.accepts(&other)? {
+                return Ok(true);
+            }
+        }
+
         Ok(match other.get_type()? {
             ValueType::String => {
                 Type::String == self
                     || Type::CharSequence == self
                     || (other.coerce_to_string()?.utf16_len()? == 1 && self.is_char())
+                    || self.is_char_array()
             }
             ValueType::Number => {
                 self.is_int()
@@ -62,10 +282,26 @@ impl JsTypeEq for JavaType {
             ValueType::BigInt => self.is_long(),
             ValueType::Null | ValueType::Undefined => !self.is_primitive(),
             ValueType::Object => {
-                if (other.is_array()? || other.is_typedarray()?)
-                    && self.is_array()
-                    && !other.is_buffer()?
+                if let Some(hint) = JavaTypeHint::from_js(env, &other)? {
+                    hint.matches(self, true).map_napi_err(Some(*env))?
+                } else if CollectionKind::from_type(self)
+                    .map(|kind| kind.accepts(env, &other))
+                    .transpose()?
+                    .unwrap_or_default()
+                    || (accepts_promise(self) && is_thenable(&other)?)
                 {
+                    true
+                } else if other.is_date()? {
+                    DateKind::from_type(self).is_some() || self.is_long()
+                } else if other.is_typedarray()? {
+                    match self.inner() {
+                        Some(inner) if self.is_array() => accepts_typed_array(
+                            &inner.lock().unwrap(),
+                            get_typed_array_type(&other)?,
+                        ),
+                        _ => false,
+                    }
+                } else if other.is_array()? && self.is_array() {
                     let arr = unsafe { other.cast::<JsTypedArray>() };
                     if arr.get_array_length()? == 0 {
                         true
@@ -123,7 +359,10 @@ impl JsTypeEq for JavaType {
                     false
                 }
             }
-            // other is a function, a symbol or unknown
+            ValueType::Function => {
+                get_functional_method(self).is_some() && is_plain_function(&other)?
+            }
+            // other is a symbol or unknown
             _ => false,
         })
     }
@@ -135,6 +374,7 @@ pub trait NapiToJava {
         env: &'a JavaEnv,
         node_env: &'a Env,
         value: JsUnknown,
+        config: &Config,
     ) -> ResultType<Option<JavaObject<'a>>>;
 
     fn convert_to_java_value<'a>(
@@ -142,6 +382,7 @@ pub trait NapiToJava {
         env: &'a JavaEnv<'a>,
         node_env: &'a Env,
         value: JsUnknown,
+        config: &Config,
     ) -> ResultType<JavaCallResult>;
 
     fn array_to_java_value<'a>(
@@ -149,6 +390,7 @@ pub trait NapiToJava {
         env: &'a JavaEnv<'a>,
         node_env: &'a Env,
         value: JsUnknown,
+        config: &Config,
     ) -> ResultType<JavaObject<'a>>;
 }
 
@@ -158,26 +400,49 @@ impl NapiToJava for JavaType {
         env: &'a JavaEnv,
         node_env: &'a Env,
         value: JsUnknown,
+        config: &Config,
     ) -> ResultType<Option<JavaObject<'a>>> {
+        if let Some(hint) = JavaTypeHint::from_js(node_env, &value)? {
+            return hint.to_java_object(self, env);
+        } else if let Some(kind) = CollectionKind::from_type(self) {
+            if kind.accepts(node_env, &value)? {
+                return js_to_java_collection(self, kind, env, node_env, value, config).map(Some);
+            }
+        } else if accepts_promise(self) && is_thenable(&value)? {
+            return promise_to_future(env, node_env, value, config).map(Some);
+        } else if let Some(kind) = DateKind::from_type(self) {
+            if value.is_date()? {
+                return kind
+                    .to_java_object(env, date_to_millis(&value)?, config)
+                    .map(Some);
+            }
+        } else if let Some(kind) = OptionalKind::from_type(self) {
+            if kind.accepts(node_env, &value)? {
+                return js_to_java_optional(self, kind, env, node_env, value, config).map(Some);
+            }
+        } else if let Some(kind) = BigNumberKind::from_type(self) {
+            if kind.accepts(&value)? {
+                return kind.to_java_object(env, value).map(Some);
+            }
+        }
+
         Ok(Some(match self.type_enum() {
             Type::LangInteger | Type::Integer => {
                 if value.get_type()? == ValueType::Object {
                     JavaObject::from(value.into_java_object(node_env)?)
                 } else {
-                    let val = value.coerce_to_number()?.get_int32()?;
+                    let val = checked_number(value.coerce_to_number()?, Type::Integer, config)?
+                        .get_int32()?;
                     JavaObject::from(LocalJavaObject::from_i32(env, val)?)
                 }
             }
             Type::LangLong | Type::Long => {
-                if value.get_type()? == ValueType::Object {
+                if value.is_date()? {
+                    LocalJavaObject::from_i64(env, date_to_millis(&value)?)?.into()
+                } else if value.get_type()? == ValueType::Object {
                     JavaObject::from(value.into_java_object(node_env)?)
                 } else {
-                    let val = if value.get_type()? == ValueType::BigInt {
-                        unsafe { value.cast::<JsBigInt>() }.get_i64()?.0
-                    } else {
-                        value.coerce_to_number()?.get_int64()?
-                    };
-
+                    let val = get_long(value, config)?;
                     LocalJavaObject::from_i64(env, val)?.into()
                 }
             }
@@ -223,7 +488,8 @@ impl NapiToJava for JavaType {
                 if value.get_type()? == ValueType::Object {
                     JavaObject::from(value.into_java_object(node_env)?)
                 } else {
-                    let val = value.coerce_to_number()?.get_int32()?;
+                    let val = checked_number(value.coerce_to_number()?, Type::Byte, config)?
+                        .get_int32()?;
                     LocalJavaObject::from_byte(env, val as i8)?.into()
                 }
             }
@@ -231,7 +497,8 @@ impl NapiToJava for JavaType {
                 if value.get_type()? == ValueType::Object {
                     JavaObject::from(value.into_java_object(node_env)?)
                 } else {
-                    let val = value.coerce_to_number()?.get_int32()?;
+                    let val = checked_number(value.coerce_to_number()?, Type::Short, config)?
+                        .get_int32()?;
                     LocalJavaObject::from_i16(env, val as i16)?.into()
                 }
             }
@@ -255,21 +522,49 @@ impl NapiToJava for JavaType {
                 ValueType::Number => {
                     let number = value.coerce_to_number()?;
                     if is_integer(node_env, &number)? {
-                        let val = number.get_int32()?;
-                        LocalJavaObject::from_i32(env, val)?.into()
+                        let val = number.get_double()?;
+                        if config.strict_numbers.unwrap_or_default()
+                            && !(i32::MIN as f64..=i32::MAX as f64).contains(&val)
+                        {
+                            // Integers outside the int range are boxed as longs
+                            check_number(val, Type::Long, config)?;
+                            LocalJavaObject::from_i64(env, val as i64)?.into()
+                        } else {
+                            LocalJavaObject::from_i32(env, number.get_int32()?)?.into()
+                        }
                     } else {
                         let val = number.get_double()?;
                         LocalJavaObject::from_f64(env, val)?.into()
                     }
                 }
                 ValueType::BigInt => {
-                    let val = unsafe { value.cast::<JsBigInt>() }.get_i64()?.0;
+                    let val = get_long(value, config)?;
                     LocalJavaObject::from_i64(env, val)?.into()
                 }
                 ValueType::Object => {
                     let err_fn = |_| "Expected a java object as parameter".to_string();
 
-                    if value.is_array()? {
+                    if is_instance_of(node_env, &value, "Set")? {
+                        return js_to_java_collection(
+                            self,
+                            CollectionKind::Set,
+                            env,
+                            node_env,
+                            value,
+                            config,
+                        )
+                        .map(Some);
+                    } else if is_instance_of(node_env, &value, "Map")? {
+                        return js_to_java_collection(
+                            self,
+                            CollectionKind::Map,
+                            env,
+                            node_env,
+                            value,
+                            config,
+                        )
+                        .map(Some);
+                    } else if value.is_array()? {
                         let arr = unsafe { value.cast::<JsTypedArray>() };
                         let class = JavaClass::by_name("java/lang/Object", env)?;
                         let mut res = JavaObjectArray::new(&class, arr.get_array_length()? as _)?;
@@ -280,6 +575,7 @@ impl NapiToJava for JavaType {
                                     env,
                                     node_env,
                                     arr.get_element(i as _)?,
+                                    config,
                                 )?,
                             )?;
                         }
@@ -321,7 +617,7 @@ impl NapiToJava for JavaType {
                     return Err("Invalid value type supplied".to_string().into());
                 }
             },
-            Type::Array => self.array_to_java_value(env, node_env, value)?,
+            Type::Array => self.array_to_java_value(env, node_env, value, config)?,
             Type::Void => {
                 return Err("Cannot use 'void' as input type".into());
             }
@@ -333,12 +629,18 @@ impl NapiToJava for JavaType {
         env: &'a JavaEnv<'a>,
         node_env: &'a Env,
         value: JsUnknown,
+        config: &Config,
     ) -> ResultType<JavaCallResult> {
+        if let Some(hint) = JavaTypeHint::from_js(node_env, &value)? {
+            return hint.to_java_value(self, env);
+        }
+
         Ok(if value.get_type()? == ValueType::Object {
             match self.type_enum() {
                 Type::Integer => JavaCallResult::Integer(env.object_to_int(
                     &LocalJavaObject::from(&value.into_java_object(node_env)?, env),
                 )?),
+                Type::Long if value.is_date()? => JavaCallResult::Long(date_to_millis(&value)?),
                 Type::Long => JavaCallResult::Long(env.object_to_long(&LocalJavaObject::from(
                     &value.into_java_object(node_env)?,
                     env,
@@ -362,7 +664,7 @@ impl NapiToJava for JavaType {
                 Type::Character => JavaCallResult::Character(env.object_to_char(
                     &LocalJavaObject::from(&value.into_java_object(node_env)?, env),
                 )?),
-                _ => match self.convert_to_java_object(env, node_env, value)? {
+                _ => match self.convert_to_java_object(env, node_env, value, config)? {
                     Some(obj) => JavaCallResult::Object {
                         object: obj.try_into()?,
                         signature: self.clone(),
@@ -372,20 +674,23 @@ impl NapiToJava for JavaType {
             }
         } else {
             match self.type_enum() {
-                Type::Integer => JavaCallResult::Integer(value.coerce_to_number()?.get_int32()?),
-                Type::Long => {
-                    if value.get_type()? == ValueType::BigInt {
-                        JavaCallResult::Long(unsafe { value.cast::<JsBigInt>() }.get_i64()?.0)
-                    } else {
-                        JavaCallResult::Long(value.coerce_to_number()?.get_int64()?)
-                    }
-                }
-                Type::Short => JavaCallResult::Short(value.coerce_to_number()?.get_int32()? as i16),
+                Type::Integer => JavaCallResult::Integer(
+                    checked_number(value.coerce_to_number()?, Type::Integer, config)?
+                        .get_int32()?,
+                ),
+                Type::Long => JavaCallResult::Long(get_long(value, config)?),
+                Type::Short => JavaCallResult::Short(
+                    checked_number(value.coerce_to_number()?, Type::Short, config)?.get_int32()?
+                        as i16,
+                ),
                 Type::Double => JavaCallResult::Double(value.coerce_to_number()?.get_double()?),
                 Type::Float => {
                     JavaCallResult::Float(value.coerce_to_number()?.get_double()? as f32)
                 }
-                Type::Byte => JavaCallResult::Byte(value.coerce_to_number()?.get_int32()? as i8),
+                Type::Byte => JavaCallResult::Byte(
+                    checked_number(value.coerce_to_number()?, Type::Byte, config)?.get_int32()?
+                        as i8,
+                ),
                 Type::Character => {
                     let str = value.coerce_to_string()?.into_utf16()?;
                     let arr = str.as_slice();
@@ -397,11 +702,14 @@ impl NapiToJava for JavaType {
                 }
                 Type::Boolean => JavaCallResult::Boolean(value.coerce_to_bool()?.get_value()?),
                 _ => {
-                    if value.get_type()? == ValueType::Null
-                        || value.get_type()? == ValueType::Undefined
+                    if (value.get_type()? == ValueType::Null
+                        || value.get_type()? == ValueType::Undefined)
+                        && OptionalKind::from_type(self).is_none()
                     {
                         JavaCallResult::Null
-                    } else if let Some(obj) = self.convert_to_java_object(env, node_env, value)? {
+                    } else if let Some(obj) =
+                        self.convert_to_java_object(env, node_env, value, config)?
+                    {
                         JavaCallResult::Object {
                             object: obj.try_into()?,
                             signature: self.clone(),
@@ -419,9 +727,14 @@ impl NapiToJava for JavaType {
         env: &'a JavaEnv<'a>,
         node_env: &'a Env,
         value: JsUnknown,
+        config: &Config,
     ) -> ResultType<JavaObject<'a>> {
         if !self.is_array() {
             return Err("Type must be an array".into());
+        } else if value.is_typedarray()? {
+            let inner = self.inner().ok_or("Array value has no inner type")?;
+            let inner = inner.lock().unwrap();
+            return typed_array_to_java(env, &inner, value);
         } else if value.is_buffer()? && self.is_byte_array() {
             let buffer = unsafe { value.cast::<JsBuffer>() }.into_value()?;
 
@@ -436,6 +749,11 @@ impl NapiToJava for JavaType {
             }
 
             return Ok(JavaByteArray::new(env, &vec)?.into());
+        } else if value.get_type()? == ValueType::String && self.is_char_array() {
+            // The utf-16 buffer contains a trailing null character
+            let string = value.coerce_to_string()?.into_utf16()?;
+            let chars = string.as_slice().split_last().map(|(_, chars)| chars);
+            return Ok(JavaCharArray::new(env, chars.unwrap_or_default())?.into());
         } else if !value.is_array()? {
             return Err("Value must be an array".into());
         }
@@ -453,7 +771,8 @@ impl NapiToJava for JavaType {
             Type::Integer => {
                 let mut res: Vec<i32> = vec![];
                 for i in 0..length {
-                    res.push(array.get_element::<JsNumber>(i)?.get_int32()?);
+                    let element = array.get_element::<JsNumber>(i)?;
+                    res.push(checked_number(element, Type::Integer, config)?.get_int32()?);
                 }
 
                 JavaIntArray::new(env, &res)?.into()
@@ -461,7 +780,7 @@ impl NapiToJava for JavaType {
             Type::Long => {
                 let mut res: Vec<i64> = vec![];
                 for i in 0..length {
-                    res.push(array.get_element::<JsNumber>(i)?.get_int64()?);
+                    res.push(get_long(array.get_element::<JsUnknown>(i)?, config)?);
                 }
 
                 JavaLongArray::new(env, &res)?.into()
@@ -485,7 +804,8 @@ impl NapiToJava for JavaType {
             Type::Byte => {
                 let mut res: Vec<i8> = vec![];
                 for i in 0..length {
-                    res.push(array.get_element::<JsNumber>(i)?.get_int32()? as i8);
+                    let element = array.get_element::<JsNumber>(i)?;
+                    res.push(checked_number(element, Type::Byte, config)?.get_int32()? as i8);
                 }
 
                 JavaByteArray::new(env, &res)?.into()
@@ -493,7 +813,8 @@ impl NapiToJava for JavaType {
             Type::Short => {
                 let mut res: Vec<i16> = vec![];
                 for i in 0..length {
-                    res.push(array.get_element::<JsNumber>(i)?.get_int32()? as i16);
+                    let element = array.get_element::<JsNumber>(i)?;
+                    res.push(checked_number(element, Type::Short, config)?.get_int32()? as i16);
                 }
 
                 JavaShortArray::new(env, &res)?.into()
@@ -529,7 +850,12 @@ impl NapiToJava for JavaType {
                 for i in 0..length {
                     res.set(
                         i as i32,
-                        inner.convert_to_java_object(env, node_env, array.get_element(i)?)?,
+                        inner.convert_to_java_object(
+                            env,
+                            node_env,
+                            array.get_element(i)?,
+                            config,
+                        )?,
                     )?;
                 }
 
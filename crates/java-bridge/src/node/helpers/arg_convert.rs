@@ -1,29 +1,67 @@
+use crate::node::config::Config;
+use crate::node::extensions::java_call_result_ext::ToNapiValue;
 use crate::node::extensions::java_type_ext::NapiToJava;
-use crate::node::helpers::napi_error::MapToNapiError;
+use crate::node::helpers::functional_interfaces::get_functional_method;
+use crate::node::helpers::napi_error::{MapToNapiError, StrIntoNapiError};
+use crate::node::helpers::typed_arrays::copy_java_array_to_typed_array;
+use crate::node::interface_proxy::function_proxy::{
+    function_to_proxy, is_plain_function, FunctionProxies,
+};
+use crate::node::java_out_argument::{get_argument, JavaOutArgument};
+use crate::node::util::helpers::ResultType;
 use java_rs::java_call_result::JavaCallResult;
 use java_rs::java_env::JavaEnv;
-use java_rs::java_type::JavaType;
+use java_rs::java_type::{JavaType, Type};
 use java_rs::objects::args::{AsJavaArg, JavaArg};
-use napi::{CallContext, JsUnknown};
+use java_rs::objects::array::{JavaArray, JavaByteArray};
+use java_rs::objects::object::{GlobalJavaObject, LocalJavaObject};
+use napi::{CallContext, Env, JsObject, JsUnknown, Ref};
 
+/// Convert the arguments of a call to java values. Javascript functions
+/// passed as functional interfaces are converted to interface proxies,
+/// which are returned alongside the arguments.
 pub fn call_context_to_java_args<'a>(
     ctx: &'a CallContext,
     signatures: &'a [JavaType],
     env: &'a JavaEnv<'a>,
-) -> napi::Result<Vec<JavaCallResult>> {
+    config: &Config,
+) -> napi::Result<(Vec<JavaCallResult>, FunctionProxies)> {
     let mut res: Vec<JavaCallResult> = vec![];
+    let mut function_proxies: FunctionProxies = vec![];
     for i in 0..signatures.len() {
-        let js_value: JsUnknown = ctx.get(i)?;
+        let js_value = get_argument(ctx, i)?;
         let signature = signatures.get(i).unwrap();
+
+        if let Some(method) = get_functional_method(signature) {
+            if is_plain_function(&js_value)? {
+                let function = unsafe { js_value.cast() };
+                let proxy = function_to_proxy(env, ctx.env, signature, method, function)
+                    .map_napi_err(Some(*ctx.env))?;
+
+                res.insert(
+                    i,
+                    JavaCallResult::Object {
+                        object: proxy
+                            .java_proxy()
+                            .cloned()
+                            .ok_or("The proxy has already been destroyed".into_napi_err())?,
+                        signature: signature.clone(),
+                    },
+                );
+                function_proxies.push(proxy);
+                continue;
+            }
+        }
+
         res.insert(
             i,
             signature
-                .convert_to_java_value(env, ctx.env, js_value)
+                .convert_to_java_value(env, ctx.env, js_value, config)
                 .map_napi_err(Some(*ctx.env))?,
         );
     }
 
-    Ok(res)
+    Ok((res, function_proxies))
 }
 
 pub fn call_results_to_args(args: &'_ [JavaCallResult]) -> Vec<JavaArg<'_>> {
@@ -31,3 +69,114 @@ pub fn call_results_to_args(args: &'_ [JavaCallResult]) -> Vec<JavaArg<'_>> {
         .map(|arg| arg.as_arg())
         .collect::<Vec<JavaArg>>()
 }
+
+/// A javascript array passed to java whose contents
+/// should be updated with the contents of the java
+/// array once the call has finished.
+pub struct ArrayWriteBack {
+    value: Ref<()>,
+    array: GlobalJavaObject,
+    signature: JavaType,
+}
+
+impl ArrayWriteBack {
+    fn write_back(&self, env: &Env, j_env: &JavaEnv, config: &Config) -> ResultType<()> {
+        let value: JsUnknown = env.get_reference_value(&self.value)?;
+        let array = JavaArray::from(LocalJavaObject::from(&self.array, j_env));
+        let inner = self
+            .signature
+            .inner()
+            .ok_or("Array value has no inner type")?;
+        let inner = inner.lock().unwrap().clone();
+
+        if value.is_typedarray()? {
+            return copy_java_array_to_typed_array(array, &inner, value);
+        }
+
+        let mut target = value.coerce_to_object()?;
+        if inner.type_enum() == Type::Byte {
+            // Keep the signed values javascript arrays passed as byte arrays
+            // contain, instead of the unsigned values of converted buffers
+            for (i, byte) in JavaByteArray::from(array).get_data()?.iter().enumerate() {
+                target.set_element(i as u32, env.create_int32(*byte as i32)?)?;
+            }
+
+            return Ok(());
+        }
+
+        // Plain arrays should always be updated with plain javascript values
+        let config = Config {
+            typed_arrays: Some(false),
+            ..config.clone()
+        };
+        let converted = JavaCallResult::Object {
+            object: self.array.clone(),
+            signature: self.signature.clone(),
+        }
+        .to_napi_value(j_env, env, &config)?;
+        let converted = unsafe { converted.cast::<JsObject>() };
+
+        for i in 0..array.len()? as u32 {
+            target.set_element(i, converted.get_element::<JsUnknown>(i)?)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Get the array arguments which should be updated after the call.
+/// These are all arguments wrapped in an out argument and all
+/// arrays and typed arrays if the `write_back_arrays` option is set.
+pub fn get_array_write_backs(
+    ctx: &CallContext,
+    signatures: &[JavaType],
+    args: &[JavaCallResult],
+    config: &Config,
+) -> napi::Result<Vec<ArrayWriteBack>> {
+    let write_back_arrays = config.write_back_arrays.unwrap_or_default();
+    let mut res = vec![];
+
+    for (i, (signature, arg)) in signatures.iter().zip(args).enumerate() {
+        let JavaCallResult::Object { object, .. } = arg else {
+            continue;
+        };
+
+        let value: JsUnknown = ctx.get(i)?;
+        let is_out = JavaOutArgument::from_js(ctx.env, &value)?.is_some();
+        let value = get_argument(ctx, i)?;
+
+        if signature.is_array()
+            && (is_out || (write_back_arrays && (value.is_array()? || value.is_typedarray()?)))
+        {
+            res.push(ArrayWriteBack {
+                value: ctx.env.create_reference(value)?,
+                array: object.clone(),
+                signature: signature.clone(),
+            });
+        }
+    }
+
+    Ok(res)
+}
+
+/// Update the javascript arrays with the contents of the java arrays
+/// passed to the call and release the references to the arrays.
+pub fn apply_array_write_backs(
+    env: &Env,
+    j_env: &JavaEnv,
+    write_backs: Vec<ArrayWriteBack>,
+    config: &Config,
+) -> napi::Result<()> {
+    let mut res = Ok(());
+    for mut write_back in write_backs {
+        if res.is_ok() {
+            res = write_back
+                .write_back(env, j_env, config)
+                .map_napi_err(Some(*env));
+        }
+
+        write_back.value.unref(*env)?;
+    }
+
+    res
+}
@@ -0,0 +1,227 @@
+use crate::node::config::BigDecimalMode;
+use crate::node::java_class_instance::OBJECT_PROPERTY;
+use crate::node::util::helpers::ResultType;
+use java_rs::java_env::JavaEnv;
+use java_rs::java_type::JavaType;
+use java_rs::objects::args::AsJavaArg;
+use java_rs::objects::array::JavaByteArray;
+use java_rs::objects::class::JavaClass;
+use java_rs::objects::java_object::JavaObject;
+use java_rs::objects::object::{GlobalJavaObject, LocalJavaObject};
+use java_rs::objects::string::JavaString;
+use java_rs::objects::value::JavaInt;
+use java_rs::traits::IsInstanceOf;
+use napi::{Env, JsBigInt, JsNumber, JsObject, JsUnknown, ValueType};
+
+/// The name of the property containing the unscaled
+/// value of a `java.math.BigDecimal` in object form.
+const UNSCALED_PROPERTY: &str = "unscaled";
+/// The name of the property containing the scale
+/// of a `java.math.BigDecimal` in object form.
+const SCALE_PROPERTY: &str = "scale";
+
+/// The arbitrary precision number types of `java.math`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BigNumberKind {
+    /// `java.math.BigInteger`
+    Integer,
+    /// `java.math.BigDecimal`
+    Decimal,
+}
+
+impl BigNumberKind {
+    /// Get the big number kind of a java parameter type.
+    /// Returns `None` if `java_type` is neither
+    /// a `BigInteger` nor a `BigDecimal`.
+    pub fn from_type(java_type: &JavaType) -> Option<Self> {
+        match java_type.to_string().as_str() {
+            "java.math.BigInteger" => Some(Self::Integer),
+            "java.math.BigDecimal" => Some(Self::Decimal),
+            _ => None,
+        }
+    }
+
+    /// Check if `value` can be converted to a number of this kind.
+    /// `BigInt`s and integral numbers are accepted by both kinds,
+    /// `BigDecimal`s additionally accept any number, decimal strings
+    /// and objects in the form `{ unscaled, scale }`.
+    pub fn accepts(self, value: &JsUnknown) -> napi::Result<bool> {
+        Ok(match value.get_type()? {
+            ValueType::BigInt => true,
+            ValueType::Number => {
+                self == Self::Decimal
+                    || unsafe { value.cast::<JsNumber>() }.get_double()?.fract() == 0.0
+            }
+            ValueType::String => self == Self::Decimal,
+            ValueType::Object => {
+                let object = unsafe { value.cast::<JsObject>() };
+                self == Self::Decimal
+                    && object.has_named_property(UNSCALED_PROPERTY)?
+                    && !object.has_named_property(OBJECT_PROPERTY)?
+            }
+            _ => false,
+        })
+    }
+
+    /// Convert `value` to a java number of this kind.
+    pub fn to_java_object<'a>(
+        self,
+        env: &'a JavaEnv<'a>,
+        value: JsUnknown,
+    ) -> ResultType<JavaObject<'a>> {
+        if self == Self::Integer {
+            return Ok(JavaObject::from(js_to_big_integer(env, value)?));
+        }
+
+        let class = JavaClass::by_name("java/math/BigDecimal", env)?;
+        let decimal = match value.get_type()? {
+            ValueType::BigInt => {
+                let unscaled = js_to_big_integer(env, value)?;
+                class
+                    .get_constructor("(Ljava/math/BigInteger;)V")?
+                    .new_instance(env, &[JavaObject::from(&unscaled).as_arg()])?
+            }
+            ValueType::Object => {
+                let object = value.coerce_to_object()?;
+                let unscaled =
+                    js_to_big_integer(env, object.get_named_property(UNSCALED_PROPERTY)?)?;
+                let scale = object
+                    .get_named_property::<JsNumber>(SCALE_PROPERTY)?
+                    .get_int32()?;
+
+                class
+                    .get_constructor("(Ljava/math/BigInteger;I)V")?
+                    .new_instance(
+                        env,
+                        &[
+                            JavaObject::from(&unscaled).as_arg(),
+                            JavaInt::new(scale).as_arg(),
+                        ],
+                    )?
+            }
+            _ => {
+                let string = value.coerce_to_string()?.into_utf8()?.into_owned()?;
+                class
+                    .get_constructor("(Ljava/lang/String;)V")?
+                    .new_instance(env, &[JavaString::from_string(string, env)?.as_arg()])?
+            }
+        };
+
+        Ok(JavaObject::from(GlobalJavaObject::try_from(decimal)?))
+    }
+}
+
+/// Convert a javascript `BigInt` or an integral number to a `java.math.BigInteger`.
+fn js_to_big_integer(env: &JavaEnv, value: JsUnknown) -> ResultType<GlobalJavaObject> {
+    let class = JavaClass::by_name("java/math/BigInteger", env)?;
+    let res = match value.get_type()? {
+        ValueType::BigInt => {
+            let (negative, words) = unsafe { value.cast::<JsBigInt>() }.get_words()?;
+            let magnitude = words
+                .iter()
+                .rev()
+                .flat_map(|word| word.to_be_bytes())
+                .map(|byte| byte as i8)
+                .collect::<Vec<_>>();
+
+            let magnitude = JavaByteArray::new(env, &magnitude)?;
+            class.get_constructor("(I[B)V")?.new_instance(
+                env,
+                &[
+                    JavaInt::new(if negative { -1 } else { 1 }).as_arg(),
+                    magnitude.as_arg(),
+                ],
+            )?
+        }
+        ValueType::Number => {
+            let number = value.coerce_to_number()?.get_double()?;
+            if !number.is_finite() || number.fract() != 0.0 {
+                return Err(format!("{} cannot be converted to a BigInteger", number).into());
+            }
+
+            class
+                .get_constructor("(Ljava/lang/String;)V")?
+                .new_instance(
+                    env,
+                    &[JavaString::from_string(format!("{:.0}", number), env)?.as_arg()],
+                )?
+        }
+        _ => return Err("Expected a BigInt or a number".into()),
+    };
+
+    GlobalJavaObject::try_from(res)
+}
+
+/// Convert a `java.math.BigInteger` to a javascript `BigInt`.
+fn big_integer_to_js(object: &LocalJavaObject, j_env: &JavaEnv, env: &Env) -> ResultType<JsBigInt> {
+    let class = JavaClass::by_name("java/math/BigInteger", j_env)?;
+    let negative = class
+        .get_int_method("signum", "()I")?
+        .call(JavaObject::from(object), &[])?
+        < 0;
+    let abs = class
+        .get_object_method("abs", "()Ljava/math/BigInteger;")?
+        .call(JavaObject::from(object), &[])?
+        .ok_or("BigInteger.abs() returned null".to_string())?;
+    let bytes = class
+        .get_object_method("toByteArray", "()[B")?
+        .call(JavaObject::from(&abs), &[])?
+        .ok_or("BigInteger.toByteArray() returned null".to_string())?;
+
+    let bytes = JavaByteArray::from(bytes).get_data()?;
+    let padding = (8 - bytes.len() % 8) % 8;
+    let mut padded = vec![0u8; padding];
+    padded.extend(bytes.into_iter().map(|byte| byte as u8));
+
+    let words = padded
+        .rchunks(8)
+        .map(|chunk| u64::from_be_bytes(chunk.try_into().unwrap()))
+        .collect();
+
+    Ok(env.create_bigint_from_words(negative, words)?)
+}
+
+/// Convert a `java.math.BigInteger` to a javascript `BigInt` and a
+/// `java.math.BigDecimal` to a decimal string or an object in the
+/// form `{ unscaled, scale }`, depending on `mode`.
+/// Returns `None` if `object` is neither.
+pub fn big_number_to_napi_value(
+    object: &GlobalJavaObject,
+    j_env: &JavaEnv,
+    env: &Env,
+    mode: BigDecimalMode,
+) -> ResultType<Option<JsUnknown>> {
+    let local = LocalJavaObject::from(object, j_env);
+    if object.is_instance_of("java/math/BigInteger")? {
+        return Ok(Some(big_integer_to_js(&local, j_env, env)?.into_unknown()?));
+    } else if !object.is_instance_of("java/math/BigDecimal")? {
+        return Ok(None);
+    }
+
+    let class = JavaClass::by_name("java/math/BigDecimal", j_env)?;
+    Ok(Some(match mode {
+        BigDecimalMode::String => {
+            let string = class
+                .get_object_method("toPlainString", "()Ljava/lang/String;")?
+                .call(JavaObject::from(&local), &[])?
+                .ok_or("BigDecimal.toPlainString() returned null".to_string())?;
+
+            env.create_string_from_std(JavaString::try_from(string)?.to_string()?)?
+                .into_unknown()
+        }
+        BigDecimalMode::Object => {
+            let unscaled = class
+                .get_object_method("unscaledValue", "()Ljava/math/BigInteger;")?
+                .call(JavaObject::from(&local), &[])?
+                .ok_or("BigDecimal.unscaledValue() returned null".to_string())?;
+            let scale = class
+                .get_int_method("scale", "()I")?
+                .call(JavaObject::from(&local), &[])?;
+
+            let mut res = env.create_object()?;
+            res.set_named_property(UNSCALED_PROPERTY, big_integer_to_js(&unscaled, j_env, env)?)?;
+            res.set_named_property(SCALE_PROPERTY, env.create_int32(scale)?)?;
+            res.into_unknown()
+        }
+    }))
+}
@@ -0,0 +1,124 @@
+use crate::node::java_class_instance::OBJECT_PROPERTY;
+use java_rs::java_type::JavaType;
+use napi::{Env, JsFunction, JsObject, JsUnknown, ValueType};
+
+/// The kind of a java collection a javascript value may be converted to.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CollectionKind {
+    List,
+    Set,
+    Map,
+}
+
+impl CollectionKind {
+    /// Get the collection kind of a java parameter type.
+    /// Returns `None` if javascript collections cannot
+    /// be converted to a value of type `java_type`.
+    pub fn from_type(java_type: &JavaType) -> Option<Self> {
+        match java_type.to_string().as_str() {
+            "java.lang.Iterable"
+            | "java.util.Collection"
+            | "java.util.SequencedCollection"
+            | "java.util.AbstractCollection"
+            | "java.util.List"
+            | "java.util.AbstractList"
+            | "java.util.ArrayList" => Some(Self::List),
+            "java.util.Set"
+            | "java.util.SequencedSet"
+            | "java.util.AbstractSet"
+            | "java.util.HashSet"
+            | "java.util.LinkedHashSet" => Some(Self::Set),
+            "java.util.Map"
+            | "java.util.SequencedMap"
+            | "java.util.AbstractMap"
+            | "java.util.HashMap"
+            | "java.util.LinkedHashMap" => Some(Self::Map),
+            _ => None,
+        }
+    }
+
+    /// The java class used to create collections of this kind.
+    pub fn implementation(&self) -> &'static str {
+        match self {
+            Self::List => "java/util/ArrayList",
+            Self::Set => "java/util/LinkedHashSet",
+            Self::Map => "java/util/LinkedHashMap",
+        }
+    }
+
+    /// Check if `value` can be converted to a java collection of this kind.
+    /// Arrays and `Set`s may be converted to lists and sets,
+    /// `Map`s and plain objects may be converted to maps.
+    pub fn accepts(&self, env: &Env, value: &JsUnknown) -> napi::Result<bool> {
+        if value.get_type()? != ValueType::Object {
+            return Ok(false);
+        }
+
+        Ok(match self {
+            Self::List | Self::Set => {
+                (value.is_array()? && !value.is_buffer()?) || is_instance_of(env, value, "Set")?
+            }
+            Self::Map => is_instance_of(env, value, "Map")? || is_plain_object(env, value)?,
+        })
+    }
+}
+
+/// Check if `value` is an instance of the global javascript class `name`.
+pub fn is_instance_of(env: &Env, value: &JsUnknown, name: &str) -> napi::Result<bool> {
+    let constructor: JsFunction = env.get_global()?.get_named_property(name)?;
+    value.instanceof(constructor)
+}
+
+/// Check if `value` is a plain javascript object, meaning its
+/// prototype is either `Object.prototype` or `null`.
+/// Instances of java classes are never plain objects,
+/// even though their prototype is `Object.prototype`.
+pub fn is_plain_object(env: &Env, value: &JsUnknown) -> napi::Result<bool> {
+    if value.get_type()? != ValueType::Object || value.is_array()? {
+        return Ok(false);
+    }
+
+    let object = unsafe { value.cast::<JsObject>() };
+    if object.has_named_property(OBJECT_PROPERTY)? {
+        return Ok(false);
+    }
+
+    let object_class = env
+        .get_global()?
+        .get_named_property::<JsFunction>("Object")?
+        .coerce_to_object()?;
+    let get_prototype_of: JsFunction = object_class.get_named_property("getPrototypeOf")?;
+    let prototype = get_prototype_of.call(None, &[value])?;
+
+    if prototype.get_type()? == ValueType::Null {
+        return Ok(true);
+    }
+
+    let object_prototype: JsUnknown = object_class.get_named_property("prototype")?;
+    env.strict_equals(prototype, object_prototype)
+}
+
+/// Convert a javascript array, `Set`, `Map` or plain object to an array.
+/// `Map`s and plain objects are converted to an array of `[key, value]` pairs.
+pub fn collection_to_array(env: &Env, value: &JsUnknown) -> napi::Result<JsObject> {
+    let (class, function) = if is_plain_object(env, value)? {
+        ("Object", "entries")
+    } else {
+        ("Array", "from")
+    };
+
+    let class = env
+        .get_global()?
+        .get_named_property::<JsFunction>(class)?
+        .coerce_to_object()?;
+    let function: JsFunction = class.get_named_property(function)?;
+
+    function.call(None, &[value])?.coerce_to_object()
+}
+
+/// Create a new instance of the global javascript class `name`
+/// (`Set` or `Map`) from an array of values or `[key, value]` pairs.
+pub fn new_from_array(env: &Env, name: &str, values: JsObject) -> napi::Result<JsUnknown> {
+    let constructor: JsFunction = env.get_global()?.get_named_property(name)?;
+    Ok(constructor.new_instance(&[values])?.into_unknown())
+}
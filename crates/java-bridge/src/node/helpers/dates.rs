@@ -0,0 +1,136 @@
+use crate::node::config::Config;
+use crate::node::util::helpers::ResultType;
+use java_rs::java_env::JavaEnv;
+use java_rs::java_type::JavaType;
+use java_rs::objects::args::AsJavaArg;
+use java_rs::objects::class::JavaClass;
+use java_rs::objects::java_object::JavaObject;
+use java_rs::objects::object::GlobalJavaObject;
+use java_rs::objects::string::JavaString;
+use java_rs::objects::value::JavaLong;
+use java_rs::traits::IsInstanceOf;
+use napi::{JsDate, JsUnknown};
+
+/// The java date and time types a javascript `Date` may be converted to.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DateKind {
+    /// `java.util.Date`
+    Date,
+    /// `java.time.Instant`
+    Instant,
+    /// `java.time.LocalDateTime`, using the configured time zone
+    LocalDateTime,
+    /// `java.time.ZonedDateTime`, using the configured time zone
+    ZonedDateTime,
+}
+
+impl DateKind {
+    /// Get the date kind of a java parameter type.
+    /// Returns `None` if javascript dates cannot
+    /// be converted to a value of type `java_type`.
+    pub fn from_type(java_type: &JavaType) -> Option<Self> {
+        match java_type.to_string().as_str() {
+            "java.util.Date" => Some(Self::Date),
+            "java.time.Instant" => Some(Self::Instant),
+            "java.time.LocalDateTime" => Some(Self::LocalDateTime),
+            "java.time.ZonedDateTime" => Some(Self::ZonedDateTime),
+            _ => None,
+        }
+    }
+
+    /// Create a java value of this kind from milliseconds since the epoch.
+    pub fn to_java_object<'a>(
+        self,
+        env: &'a JavaEnv<'a>,
+        millis: i64,
+        config: &Config,
+    ) -> ResultType<JavaObject<'a>> {
+        if self == Self::Date {
+            let class = JavaClass::by_name("java/util/Date", env)?;
+            let date = class
+                .get_constructor("(J)V")?
+                .new_instance(env, &[JavaLong::new(millis).as_arg()])?;
+
+            return Ok(JavaObject::from(GlobalJavaObject::try_from(date)?));
+        }
+
+        let instant_class = JavaClass::by_name("java/time/Instant", env)?;
+        let instant = instant_class
+            .get_static_object_method("ofEpochMilli", "(J)Ljava/time/Instant;")?
+            .call(&[JavaLong::new(millis).as_arg()])?
+            .ok_or("Instant.ofEpochMilli() returned null".to_string())?;
+
+        if self == Self::Instant {
+            return Ok(JavaObject::from(GlobalJavaObject::try_from(instant)?));
+        }
+
+        let zone = get_zone(env, config)?;
+        let zone = JavaObject::from(&zone);
+        let zoned_class = JavaClass::by_name("java/time/ZonedDateTime", env)?;
+        let zoned = zoned_class
+            .get_static_object_method(
+                "ofInstant",
+                "(Ljava/time/Instant;Ljava/time/ZoneId;)Ljava/time/ZonedDateTime;",
+            )?
+            .call(&[instant.as_arg(), zone.as_arg()])?
+            .ok_or("ZonedDateTime.ofInstant() returned null".to_string())?;
+
+        let res = if self == Self::ZonedDateTime {
+            zoned
+        } else {
+            zoned_class
+                .get_object_method("toLocalDateTime", "()Ljava/time/LocalDateTime;")?
+                .call(JavaObject::from(&zoned), &[])?
+                .ok_or("ZonedDateTime.toLocalDateTime() returned null".to_string())?
+        };
+
+        Ok(JavaObject::from(GlobalJavaObject::try_from(res)?))
+    }
+}
+
+/// Get the configured `java.time.ZoneId` or the system default zone.
+fn get_zone(env: &JavaEnv, config: &Config) -> ResultType<GlobalJavaObject> {
+    let class = JavaClass::by_name("java/time/ZoneId", env)?;
+    let zone = match config.time_zone.as_ref() {
+        Some(zone) => class
+            .get_static_object_method("of", "(Ljava/lang/String;)Ljava/time/ZoneId;")?
+            .call(&[JavaString::from_string(zone.clone(), env)?.as_arg()])?,
+        None => class
+            .get_static_object_method("systemDefault", "()Ljava/time/ZoneId;")?
+            .call(&[])?,
+    };
+
+    let zone = zone.ok_or("Could not get the time zone".to_string())?;
+    GlobalJavaObject::try_from(zone)
+}
+
+/// Get the milliseconds since the epoch of a javascript `Date`.
+/// Returns an error if the date is invalid.
+pub fn date_to_millis(value: &JsUnknown) -> ResultType<i64> {
+    let date = unsafe { value.cast::<JsDate>() };
+    let millis = date.value_of()?;
+    if millis.is_nan() {
+        return Err("Cannot convert an invalid Date".into());
+    }
+
+    Ok(millis as i64)
+}
+
+/// Get the milliseconds since the epoch of a `java.util.Date`
+/// or a `java.time.Instant`. Returns `None` if `object` is neither.
+pub fn java_date_to_millis(object: &GlobalJavaObject, env: &JavaEnv) -> ResultType<Option<i64>> {
+    let (class_name, method) = if object.is_instance_of("java/util/Date")? {
+        ("java/util/Date", "getTime")
+    } else if object.is_instance_of("java/time/Instant")? {
+        ("java/time/Instant", "toEpochMilli")
+    } else {
+        return Ok(None);
+    };
+
+    let class = JavaClass::by_name(class_name, env)?;
+    let millis = class
+        .get_long_method(method, "()J")?
+        .call(JavaObject::from(object), &[])?;
+
+    Ok(Some(millis))
+}
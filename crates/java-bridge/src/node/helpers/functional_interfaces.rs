@@ -0,0 +1,124 @@
+use crate::node::util::helpers::ResultType;
+use java_rs::java_env::JavaEnv;
+use java_rs::java_type::{JavaType, Type};
+use java_rs::objects::args::AsJavaArg;
+use java_rs::objects::array::JavaObjectArray;
+use java_rs::objects::class::JavaClass;
+use java_rs::objects::java_object::JavaObject;
+use java_rs::objects::value::JavaInt;
+use java_rs::util::conversion::{get_method_name, get_method_parameters};
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+lazy_static! {
+    /// The functional methods of all parameter types resolved so far,
+    /// by the name of the type. `None` if a type is not a functional interface.
+    static ref FUNCTIONAL_METHODS: Mutex<HashMap<String, Option<String>>> =
+        Mutex::new(HashMap::new());
+}
+
+/// The public methods of `java.lang.Object` which may be declared
+/// by interfaces without counting as abstract methods of the interface.
+const OBJECT_METHODS: [(&str, &[&str]); 3] = [
+    ("equals", &["java.lang.Object"]),
+    ("hashCode", &[]),
+    ("toString", &[]),
+];
+
+/// Get the name of the single abstract method of a functional interface.
+/// Returns `None` if `java_type` is not a functional interface or if it has
+/// not been resolved using [`resolve_functional_interfaces`] yet.
+pub fn get_functional_method(java_type: &JavaType) -> Option<String> {
+    if java_type.type_enum() != Type::Object {
+        return None;
+    }
+
+    FUNCTIONAL_METHODS
+        .lock()
+        .unwrap()
+        .get(&java_type.to_string())
+        .cloned()
+        .flatten()
+}
+
+/// Find out which of `parameter_types` are functional interfaces,
+/// allowing javascript functions to be passed as these parameters.
+/// The results are cached, so every type is only checked once.
+pub fn resolve_functional_interfaces(
+    env: &JavaEnv,
+    parameter_types: &[JavaType],
+) -> ResultType<()> {
+    for java_type in parameter_types {
+        let name = java_type.to_string();
+        if java_type.type_enum() != Type::Object
+            || FUNCTIONAL_METHODS.lock().unwrap().contains_key(&name)
+        {
+            continue;
+        }
+
+        let method = find_functional_method(env, java_type)?;
+        FUNCTIONAL_METHODS.lock().unwrap().insert(name, method);
+    }
+
+    Ok(())
+}
+
+/// Find the single abstract method of `java_type`.
+/// Returns `None` if `java_type` is not an interface or
+/// if it does not have exactly one abstract method.
+fn find_functional_method(env: &JavaEnv, java_type: &JavaType) -> ResultType<Option<String>> {
+    let class = java_type.as_class(env)?;
+    let java_class = env.get_java_lang_class()?;
+    let is_interface = java_class
+        .get_boolean_method("isInterface", "()Z")?
+        .call(JavaObject::from(&class), &[])?;
+    if !is_interface {
+        return Ok(None);
+    }
+
+    let methods = JavaObjectArray::from(
+        java_class
+            .get_object_method("getMethods", "()[Ljava/lang/reflect/Method;")?
+            .call(JavaObject::from(&class), &[])?
+            .ok_or("Class.getMethods() returned null".to_string())?,
+    );
+
+    let method_class = JavaClass::by_name("java/lang/reflect/Method", env)?;
+    let get_modifiers = method_class.get_int_method("getModifiers", "()I")?;
+    let modifier = JavaClass::by_name("java/lang/reflect/Modifier", env)?;
+    let is_abstract = modifier.get_static_boolean_method("isAbstract", "(I)Z")?;
+
+    let mut functional_method: Option<(String, Vec<String>)> = None;
+    for i in 0..methods.len()? {
+        let method = methods
+            .get(i)?
+            .ok_or("A value in the array returned by Class.getMethods() was null".to_string())?;
+
+        let modifiers = get_modifiers.call(JavaObject::from(&method), &[])?;
+        if !is_abstract.call(&[JavaInt::new(modifiers).as_arg()])? {
+            continue;
+        }
+
+        let name = get_method_name(env, &method)?;
+        let parameters = get_method_parameters(env, &method)?
+            .iter()
+            .map(|t| t.to_string())
+            .collect::<Vec<_>>();
+        if OBJECT_METHODS
+            .iter()
+            .any(|(n, p)| *n == name && parameters.iter().eq(p.iter()))
+        {
+            continue;
+        }
+
+        // The same method may be inherited from multiple interfaces
+        let signature = (name, parameters);
+        match functional_method {
+            Some(ref existing) if *existing != signature => return Ok(None),
+            _ => functional_method = Some(signature),
+        }
+    }
+
+    Ok(functional_method.map(|(name, _)| name))
+}
@@ -1,4 +1,9 @@
 pub mod arg_convert;
+pub mod big_numbers;
+pub mod collections;
+pub mod dates;
+pub mod functional_interfaces;
 pub mod js_to_java_object;
 pub mod napi_error;
-pub mod napi_ext;
+pub mod optionals;
+pub mod typed_arrays;
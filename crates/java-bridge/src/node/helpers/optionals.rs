@@ -0,0 +1,98 @@
+use crate::node::helpers::js_to_java_object::JsIntoJavaObject;
+use crate::node::helpers::napi_error::MapToNapiError;
+use crate::node::java_class_instance::OBJECT_PROPERTY;
+use crate::node::util::helpers::ResultType;
+use java_rs::java_type::JavaType;
+use java_rs::objects::object::GlobalJavaObject;
+use java_rs::traits::IsInstanceOf;
+use napi::{Env, JsObject, JsUnknown, ValueType};
+
+/// The kind of a java optional.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum OptionalKind {
+    /// `java.util.Optional`
+    Object,
+    /// `java.util.OptionalInt`
+    Int,
+    /// `java.util.OptionalLong`
+    Long,
+    /// `java.util.OptionalDouble`
+    Double,
+}
+
+impl OptionalKind {
+    const ALL: [Self; 4] = [Self::Object, Self::Int, Self::Long, Self::Double];
+
+    /// Get the optional kind of a java parameter type.
+    /// Returns `None` if `java_type` is not an optional.
+    pub fn from_type(java_type: &JavaType) -> Option<Self> {
+        let name = java_type.to_string();
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.class_name().replace('/', ".") == name)
+    }
+
+    /// Get the optional kind of a java object.
+    /// Returns `None` if `object` is not an optional.
+    pub fn from_object(object: &GlobalJavaObject) -> ResultType<Option<Self>> {
+        for kind in Self::ALL {
+            if object.is_instance_of(kind.class_name())? {
+                return Ok(Some(kind));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// The jni name of the optional class.
+    pub fn class_name(&self) -> &'static str {
+        match self {
+            Self::Object => "java/util/Optional",
+            Self::Int => "java/util/OptionalInt",
+            Self::Long => "java/util/OptionalLong",
+            Self::Double => "java/util/OptionalDouble",
+        }
+    }
+
+    /// The primitive type contained in the optional,
+    /// or `None` for `java.util.Optional`.
+    pub fn primitive_type(&self) -> Option<&'static str> {
+        match self {
+            Self::Object => None,
+            Self::Int => Some("int"),
+            Self::Long => Some("long"),
+            Self::Double => Some("double"),
+        }
+    }
+
+    /// Check if `value` should be wrapped in an optional of this kind.
+    /// `null` and `undefined` are always wrapped, numbers are wrapped
+    /// in any optional, `BigInt`s in `java.util.Optional` and
+    /// `java.util.OptionalLong` and any other value except
+    /// existing java optionals in `java.util.Optional`.
+    pub fn accepts(&self, env: &Env, value: &JsUnknown) -> napi::Result<bool> {
+        Ok(match value.get_type()? {
+            ValueType::Null | ValueType::Undefined | ValueType::Number => true,
+            ValueType::BigInt => matches!(self, Self::Object | Self::Long),
+            ValueType::String | ValueType::Boolean => *self == Self::Object,
+            ValueType::Object => {
+                if *self != Self::Object {
+                    false
+                } else {
+                    let object = unsafe { value.cast::<JsObject>() };
+                    if object.has_named_property(OBJECT_PROPERTY)? {
+                        let object = unsafe { value.cast::<JsUnknown>() };
+                        let instance = object.into_java_object(env)?;
+
+                        !instance
+                            .is_instance_of(self.class_name())
+                            .map_napi_err(Some(*env))?
+                    } else {
+                        true
+                    }
+                }
+            }
+            _ => false,
+        })
+    }
+}
@@ -0,0 +1,202 @@
+use crate::node::util::helpers::ResultType;
+use java_rs::java_env::JavaEnv;
+use java_rs::java_type::{JavaType, Type};
+use java_rs::objects::array::{
+    JavaArray, JavaByteArray, JavaCharArray, JavaDoubleArray, JavaFloatArray, JavaIntArray,
+    JavaLongArray, JavaShortArray,
+};
+use java_rs::objects::java_object::JavaObject;
+use napi::{Env, JsTypedArray, JsTypedArrayValue, JsUnknown, TypedArrayType};
+
+/// Get the typed array type matching the primitive
+/// component type of a java array. Returns `None` if
+/// `component` has no matching typed array type.
+pub fn typed_array_type(component: &JavaType) -> Option<TypedArrayType> {
+    match component.type_enum() {
+        Type::Integer => Some(TypedArrayType::Int32),
+        Type::Long => Some(TypedArrayType::BigInt64),
+        Type::Short => Some(TypedArrayType::Int16),
+        Type::Double => Some(TypedArrayType::Float64),
+        Type::Float => Some(TypedArrayType::Float32),
+        Type::Character => Some(TypedArrayType::Uint16),
+        Type::Byte => Some(TypedArrayType::Uint8),
+        _ => None,
+    }
+}
+
+/// Check if a typed array of type `typed_array_type` can be
+/// converted to a java array with the component type `component`.
+/// `byte[]` accepts `Uint8Array`s, `Uint8ClampedArray`s and `Int8Array`s.
+pub fn accepts_typed_array(component: &JavaType, typed_array_type: TypedArrayType) -> bool {
+    if component.type_enum() == Type::Byte {
+        matches!(
+            typed_array_type,
+            TypedArrayType::Uint8 | TypedArrayType::Uint8Clamped | TypedArrayType::Int8
+        )
+    } else {
+        self::typed_array_type(component) == Some(typed_array_type)
+    }
+}
+
+/// Get the type of a javascript typed array.
+pub fn get_typed_array_type(value: &JsUnknown) -> napi::Result<TypedArrayType> {
+    let array = unsafe { value.cast::<JsTypedArray>() };
+    Ok(array.into_value()?.typedarray_type)
+}
+
+/// Get the contents of a javascript typed array.
+fn typed_array_data<T>(array: &JsTypedArrayValue) -> &[T]
+where
+    JsTypedArrayValue: AsRef<[T]>,
+{
+    // The data pointer of empty typed arrays may be null
+    if array.length == 0 {
+        &[]
+    } else {
+        array.as_ref()
+    }
+}
+
+/// Copy the contents of a javascript typed array into
+/// a new java array with the component type `component`.
+pub fn typed_array_to_java<'a>(
+    env: &'a JavaEnv<'a>,
+    component: &JavaType,
+    value: JsUnknown,
+) -> ResultType<JavaObject<'a>> {
+    let array = unsafe { value.cast::<JsTypedArray>() }.into_value()?;
+    if !accepts_typed_array(component, array.typedarray_type) {
+        return Err(format!(
+            "A {:?} typed array cannot be converted to {}[]",
+            array.typedarray_type, component
+        )
+        .into());
+    }
+
+    Ok(match component.type_enum() {
+        Type::Integer => JavaIntArray::new(env, typed_array_data(&array))?.into(),
+        Type::Long => JavaLongArray::new(env, typed_array_data(&array))?.into(),
+        Type::Short => JavaShortArray::new(env, typed_array_data(&array))?.into(),
+        Type::Double => JavaDoubleArray::new(env, typed_array_data(&array))?.into(),
+        Type::Float => JavaFloatArray::new(env, typed_array_data(&array))?.into(),
+        Type::Character => JavaCharArray::new(env, typed_array_data(&array))?.into(),
+        Type::Byte if array.typedarray_type == TypedArrayType::Int8 => {
+            JavaByteArray::new(env, typed_array_data(&array))?.into()
+        }
+        _ => {
+            let data: &[u8] = typed_array_data(&array);
+            let data = data.iter().map(|b| *b as i8).collect::<Vec<_>>();
+            JavaByteArray::new(env, &data)?.into()
+        }
+    })
+}
+
+/// Create a javascript typed array of type `typed_array_type` containing `data`.
+fn create_typed_array<T: Copy>(
+    env: &Env,
+    data: Vec<T>,
+    typed_array_type: TypedArrayType,
+) -> ResultType<JsUnknown> {
+    let size = std::mem::size_of_val(data.as_slice());
+    let mut buffer = env.create_arraybuffer(size)?;
+    // The typed array types used match the primitive java types,
+    // so the data can be copied as raw bytes
+    if size > 0 {
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, size) };
+        buffer.as_mut().copy_from_slice(bytes);
+    }
+
+    Ok(buffer
+        .into_raw()
+        .into_typedarray(typed_array_type, data.len(), 0)?
+        .into_unknown())
+}
+
+/// Convert a primitive java array with the component type
+/// `component` to a javascript typed array of type `typed_array_type`.
+pub fn java_array_to_typed_array(
+    env: &Env,
+    array: JavaArray,
+    component: &JavaType,
+    typed_array_type: TypedArrayType,
+) -> ResultType<JsUnknown> {
+    Ok(match component.type_enum() {
+        Type::Integer => {
+            create_typed_array(env, JavaIntArray::from(array).get_data()?, typed_array_type)?
+        }
+        Type::Long => create_typed_array(
+            env,
+            JavaLongArray::from(array).get_data()?,
+            typed_array_type,
+        )?,
+        Type::Short => create_typed_array(
+            env,
+            JavaShortArray::from(array).get_data()?,
+            typed_array_type,
+        )?,
+        Type::Double => create_typed_array(
+            env,
+            JavaDoubleArray::from(array).get_data()?,
+            typed_array_type,
+        )?,
+        Type::Float => create_typed_array(
+            env,
+            JavaFloatArray::from(array).get_data()?,
+            typed_array_type,
+        )?,
+        Type::Character => create_typed_array(
+            env,
+            JavaCharArray::from(array).get_data()?,
+            typed_array_type,
+        )?,
+        _ => create_typed_array(
+            env,
+            JavaByteArray::from(array).get_data()?,
+            typed_array_type,
+        )?,
+    })
+}
+
+/// Copy `data` into a javascript typed array of the same length.
+fn copy_to<T: Copy>(target: &mut JsTypedArrayValue, data: &[T])
+where
+    JsTypedArrayValue: AsMut<[T]>,
+{
+    target.as_mut().copy_from_slice(data);
+}
+
+/// Copy the contents of a primitive java array with the
+/// component type `component` into a javascript typed array.
+/// The typed array must have the same length as the java array.
+pub fn copy_java_array_to_typed_array(
+    array: JavaArray,
+    component: &JavaType,
+    value: JsUnknown,
+) -> ResultType<()> {
+    let mut target = unsafe { value.cast::<JsTypedArray>() }.into_value()?;
+    if target.length == 0 {
+        return Ok(());
+    }
+
+    match component.type_enum() {
+        Type::Integer => copy_to(&mut target, &JavaIntArray::from(array).get_data()?),
+        Type::Long => copy_to(&mut target, &JavaLongArray::from(array).get_data()?),
+        Type::Short => copy_to(&mut target, &JavaShortArray::from(array).get_data()?),
+        Type::Double => copy_to(&mut target, &JavaDoubleArray::from(array).get_data()?),
+        Type::Float => copy_to(&mut target, &JavaFloatArray::from(array).get_data()?),
+        Type::Character => copy_to(&mut target, &JavaCharArray::from(array).get_data()?),
+        Type::Byte if target.typedarray_type == TypedArrayType::Int8 => {
+            copy_to(&mut target, &JavaByteArray::from(array).get_data()?)
+        }
+        Type::Byte => {
+            let data = JavaByteArray::from(array).get_data()?;
+            let target: &mut [u8] = target.as_mut();
+            for (dest, src) in target.iter_mut().zip(data) {
+                *dest = src as u8;
+            }
+        }
+        _ => return Err(format!("Cannot copy a {}[] to a typed array", component).into()),
+    }
+
+    Ok(())
+}
@@ -41,6 +41,12 @@ impl FunctionCaller {
         Ok(())
     }
 
+    /// Drop the reference to the java instance without
+    /// invalidating it, leaving its lifetime to java.
+    pub fn release(&mut self) {
+        self.instance.take();
+    }
+
     pub fn move_to(&mut self) -> Option<FunctionCaller> {
         self.instance.take().map(FunctionCaller::new)
     }
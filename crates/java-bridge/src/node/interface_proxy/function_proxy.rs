@@ -0,0 +1,166 @@
+use crate::node::interface_proxy::interface_proxy_options::InterfaceProxyOptions;
+use crate::node::interface_proxy::java_interface_proxy::JavaInterfaceProxy;
+use crate::node::java_class_instance::CLASS_PROXY_PROPERTY;
+use crate::node::util::helpers::ResultType;
+use java_rs::java_env::JavaEnv;
+use java_rs::java_type::JavaType;
+use napi::{
+    check_status, sys, CallContext, Env, JsError, JsFunction, JsObject, JsUndefined, JsUnknown,
+    NapiRaw, NapiValue, ValueType,
+};
+use std::collections::HashMap;
+use std::ptr;
+
+/// Check if `value` is a javascript function which may be
+/// converted to a functional interface. Imported java
+/// classes are functions too, but are not converted.
+pub fn is_plain_function(value: &JsUnknown) -> napi::Result<bool> {
+    Ok(value.get_type()? == ValueType::Function
+        && !unsafe { value.cast::<JsObject>() }.has_named_property(CLASS_PROXY_PROPERTY)?)
+}
+
+/// Create `function.bind(this)`.
+fn bind<T: NapiRaw>(function: JsFunction, this: T) -> napi::Result<JsFunction> {
+    let function = function.coerce_to_object()?;
+    let bind: JsFunction = function.get_named_property("bind")?;
+    let bound = bind.call(Some(&function), &[this])?;
+
+    Ok(unsafe { bound.cast() })
+}
+
+/// Convert a value thrown by or rejected from a
+/// javascript function to an `Error`, if it isn't one.
+fn to_error(env: &Env, value: JsUnknown) -> napi::Result<JsUnknown> {
+    if value.is_error()? {
+        return Ok(value);
+    }
+
+    let message = value.coerce_to_string()?.into_utf8()?.into_owned()?;
+    Ok(JsError::from(napi::Error::from_reason(message)).into_unknown(*env))
+}
+
+/// Call `function` with `args`, returning the value it throws as an error.
+/// Unlike [`JsFunction::call`], this preserves thrown values which aren't
+/// objects, as napi can't create references to these.
+fn call_catching(
+    env: &Env,
+    function: &JsFunction,
+    args: &[JsUnknown],
+) -> napi::Result<Result<JsUnknown, JsUnknown>> {
+    let raw_args = args
+        .iter()
+        .map(|arg| unsafe { arg.raw() })
+        .collect::<Vec<_>>();
+    let mut res = ptr::null_mut();
+    let status = unsafe {
+        sys::napi_call_function(
+            env.raw(),
+            env.get_undefined()?.raw(),
+            function.raw(),
+            raw_args.len(),
+            raw_args.as_ptr(),
+            &mut res,
+        )
+    };
+
+    match status {
+        sys::Status::napi_ok => Ok(Ok(unsafe { JsUnknown::from_raw_unchecked(env.raw(), res) })),
+        sys::Status::napi_pending_exception => {
+            let mut err = ptr::null_mut();
+            check_status!(unsafe { sys::napi_get_and_clear_last_exception(env.raw(), &mut err) })?;
+            Ok(Err(unsafe {
+                JsUnknown::from_raw_unchecked(env.raw(), err)
+            }))
+        }
+        status => Err(napi::Error::new(
+            status.into(),
+            "Failed to call the proxy method".to_string(),
+        )),
+    }
+}
+
+/// Call the function bound as `this` with the arguments of an interface proxy
+/// call and pass its result to the callback. If the function returns a promise,
+/// the value the promise resolves to is passed to the callback instead.
+fn call_function(ctx: CallContext) -> napi::Result<JsUndefined> {
+    let err: JsUnknown = ctx.get(0)?;
+    if err.is_error()? {
+        return Err(err.into());
+    }
+
+    let function = ctx.this_unchecked::<JsFunction>();
+    let callback: JsFunction = ctx.get(1)?;
+    let args = (2..ctx.length)
+        .map(|i| ctx.get::<JsUnknown>(i))
+        .collect::<napi::Result<Vec<_>>>()?;
+
+    match call_catching(ctx.env, &function, &args)? {
+        Ok(res) if res.is_promise()? => {
+            let promise = res.coerce_to_object()?;
+            let then: JsFunction = promise.get_named_property("then")?;
+            let on_fulfilled = ctx.env.create_function_from_closure("onFulfilled", |ctx| {
+                let callback = ctx.this_unchecked::<JsFunction>();
+                callback.call(None, &[ctx.env.get_null()?.into_unknown(), ctx.get(0)?])
+            })?;
+            let on_rejected = ctx.env.create_function_from_closure("onRejected", |ctx| {
+                let callback = ctx.this_unchecked::<JsFunction>();
+                callback.call(None, &[to_error(ctx.env, ctx.get(0)?)?])
+            })?;
+
+            then.call(
+                Some(&promise),
+                &[
+                    bind(on_fulfilled, ctx.get::<JsFunction>(1)?)?,
+                    bind(on_rejected, ctx.get::<JsFunction>(1)?)?,
+                ],
+            )?;
+        }
+        Ok(res) => {
+            callback.call(None, &[ctx.env.get_null()?.into_unknown(), res])?;
+        }
+        Err(err) => {
+            callback.call(None, &[to_error(ctx.env, err)?])?;
+        }
+    }
+
+    ctx.env.get_undefined()
+}
+
+/// Wrap a javascript function returning a value or a promise, allowing it to
+/// be called as an interface proxy method, which passes its result to a callback.
+pub fn wrap_function(env: &Env, function: JsFunction) -> napi::Result<JsFunction> {
+    let call = env.create_function_from_closure("callFunction", call_function)?;
+    bind(call, function)
+}
+
+/// Create an interface proxy implementing the functional
+/// interface `java_type` by calling the javascript function
+/// `function` when its functional method `method` is called.
+pub fn function_to_proxy(
+    j_env: &JavaEnv,
+    env: &Env,
+    java_type: &JavaType,
+    method: String,
+    function: JsFunction,
+) -> ResultType<JavaInterfaceProxy> {
+    let methods = HashMap::from([(method, wrap_function(env, function)?)]);
+
+    let proxy = JavaInterfaceProxy::new(
+        j_env.get_java_vm()?,
+        *env,
+        vec![java_type.to_string()],
+        methods,
+        InterfaceProxyOptions::default(),
+    )?;
+
+    // The proxy is released once the call has returned,
+    // so it should not keep the process alive on its own
+    proxy.unref(env)?;
+    Ok(proxy)
+}
+
+/// The interface proxies created for the function arguments of a call.
+/// These are only owned by the call and released once it has returned.
+/// Java may keep these proxies and call them after the call has finished,
+/// in which case they stay alive until java no longer references them.
+pub type FunctionProxies = Vec<JavaInterfaceProxy>;
@@ -1,29 +1,117 @@
 use crate::node::interface_proxy::types::JsCallResult;
-use futures::channel::oneshot::Sender;
 use java_rs::java_call_result::JavaCallResult;
+use java_rs::java_type::JavaType;
 use java_rs::util::helpers::ResultType;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
 use std::sync::Mutex;
 
+/// Handles the result of a call java doesn't wait for.
+pub type ResultHandler = Box<dyn FnOnce(JsCallResult) + Send>;
+
+/// Where the result of a call is passed to.
+enum ResultTarget {
+    /// Java is waiting for the result.
+    Sender(Sender<JsCallResult>),
+    /// Java already returned, the result is passed to a handler.
+    Handler(ResultHandler),
+}
+
+impl ResultTarget {
+    fn send(self, result: JsCallResult) -> ResultType<()> {
+        match self {
+            ResultTarget::Sender(sender) => sender
+                .send(result)
+                .map_err(|_| "Could not send result to sender".into()),
+            ResultTarget::Handler(handler) => {
+                handler(result);
+                Ok(())
+            }
+        }
+    }
+}
+
 pub struct InterfaceCall {
     pub args: Vec<JavaCallResult>,
-    sender: Mutex<Option<Sender<JsCallResult>>>,
+    /// The declared return type of the called method.
+    pub return_type: JavaType,
+    target: Mutex<Option<ResultTarget>>,
+    non_blocking: bool,
+    abandoned: AtomicBool,
 }
 
 impl InterfaceCall {
-    pub fn new(args: Vec<JavaCallResult>, sender: Sender<JsCallResult>) -> Self {
+    pub fn new(
+        args: Vec<JavaCallResult>,
+        return_type: JavaType,
+        sender: Sender<JsCallResult>,
+    ) -> Self {
+        Self::with_target(args, return_type, ResultTarget::Sender(sender), false)
+    }
+
+    /// Create a call java doesn't wait for, passing its result to `handler`.
+    pub fn non_blocking(
+        args: Vec<JavaCallResult>,
+        return_type: JavaType,
+        handler: ResultHandler,
+    ) -> Self {
+        Self::with_target(args, return_type, ResultTarget::Handler(handler), true)
+    }
+
+    fn with_target(
+        args: Vec<JavaCallResult>,
+        return_type: JavaType,
+        target: ResultTarget,
+        non_blocking: bool,
+    ) -> Self {
         InterfaceCall {
             args,
-            sender: Mutex::new(Some(sender)),
+            return_type,
+            target: Mutex::new(Some(target)),
+            non_blocking,
+            abandoned: AtomicBool::new(false),
         }
     }
 
+    /// Whether java returned without waiting for the result of this call.
+    pub fn is_non_blocking(&self) -> bool {
+        self.non_blocking
+    }
+
     pub fn set_result(&self, result: JsCallResult) -> ResultType<()> {
-        self.sender
+        if self.abandoned.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let target = self
+            .target
             .lock()
             .unwrap()
             .take()
-            .ok_or("The sender was already invoked".to_string())?
-            .send(result)
-            .map_err(|_| "Could not send result to sender".into())
+            .ok_or("The sender was already invoked".to_string())?;
+        target.send(result)
+    }
+
+    /// Fail the call with `message` if no result has been set yet.
+    /// Results set after this are ignored.
+    pub fn abandon(&self, message: String) {
+        if let Some(target) = self.take_target() {
+            target.send(Err(message)).ok();
+        }
+    }
+
+    /// Stop waiting for the result of the call, ignoring results set after
+    /// this. Returns `false` if a result has already been set.
+    pub fn cancel(&self) -> bool {
+        self.take_target().is_some()
+    }
+
+    fn take_target(&self) -> Option<ResultTarget> {
+        let target = self.target.lock().unwrap().take();
+        if target.is_some() {
+            self.abandoned.store(true, Ordering::SeqCst);
+        }
+
+        target
     }
 }
@@ -1,3 +1,6 @@
+use napi::bindgen_prelude::Either;
+use napi::JsFunction;
+
 /// Options for the interface proxies
 #[napi(object)]
 #[derive(Default)]
@@ -5,4 +8,25 @@ pub struct InterfaceProxyOptions {
     /// If true, the proxy will be kept as a daemon
     /// proxy after the interface has been destroyed
     pub keep_as_daemon: Option<bool>,
+    /// The time in milliseconds java waits for a proxy method
+    /// to return before throwing a `java.util.concurrent.TimeoutException`.
+    /// Java waits indefinitely if this is not set.
+    ///
+    /// @since 2.9.0
+    pub timeout_ms: Option<u32>,
+    /// Whether calls to `void` methods should return to java immediately
+    /// instead of waiting for the javascript function to return. Either
+    /// `true` to enable this for all methods or a list of method names.
+    /// Errors thrown by these methods are passed to `onError`.
+    ///
+    /// @since 2.9.0
+    #[napi(ts_type = "boolean | string[]")]
+    pub non_blocking_void: Option<Either<bool, Vec<String>>>,
+    /// Called with the error and the method name if a
+    /// non-blocking `void` method throws an error.
+    /// These errors are ignored if this is not set.
+    ///
+    /// @since 2.9.0
+    #[napi(ts_type = "(error: Error, method: string) => void")]
+    pub on_error: Option<JsFunction>,
 }
@@ -1,3 +1,5 @@
+use crate::debug;
+use crate::node::config::Config;
 use crate::node::extensions::java_call_result_ext::ToNapiValue;
 use crate::node::extensions::java_type_ext::NapiToJava;
 use crate::node::helpers::napi_error::{MapToNapiError, NapiError};
@@ -5,14 +7,19 @@ use crate::node::interface_proxy::function_caller::FunctionCaller;
 use crate::node::interface_proxy::interface_call::InterfaceCall;
 use crate::node::interface_proxy::interface_proxy_options::InterfaceProxyOptions;
 use crate::node::interface_proxy::js_error::JsError;
+use crate::node::interface_proxy::pending_calls::{queue_call, torn_down_message};
 use crate::node::interface_proxy::proxies::{
-    find_methods_by_id, generate_proxy_id, get_daemon_proxies, get_proxies, remove_proxy,
+    count_call, find_error_handler, find_methods_by_id, generate_proxy_id, get_daemon_proxies,
+    get_proxies, remove_collected_proxy, remove_proxy,
 };
-use crate::node::interface_proxy::types::{JsCallResult, MethodsType};
-use futures::channel::oneshot::channel;
+use crate::node::interface_proxy::proxy_info::{capture_stack, ProxyInfo};
+use crate::node::interface_proxy::reentrant_calls::{
+    call_directly, can_call_directly, forward_call, register_functions, unregister_functions,
+};
+use crate::node::interface_proxy::types::{ErrorHandler, JsCallResult, MethodsType, ProxyEntry};
 use java_rs::java_call_result::JavaCallResult;
 use java_rs::java_env::JavaEnv;
-use java_rs::java_type::JavaType;
+use java_rs::java_type::{JavaType, Type};
 use java_rs::java_vm::JavaVM;
 use java_rs::objects::args::AsJavaArg;
 use java_rs::objects::array::JavaObjectArray;
@@ -21,13 +28,17 @@ use java_rs::objects::java_object::JavaObject;
 use java_rs::objects::object::{GlobalJavaObject, LocalJavaObject};
 use java_rs::objects::string::JavaString;
 use java_rs::objects::value::JavaLong;
+use java_rs::util::conversion::{get_method_parameters, get_method_return_type};
 use java_rs::util::helpers::ResultType;
 use java_rs::{function, sys};
+use napi::bindgen_prelude::{Either, ObjectFinalize};
 use napi::threadsafe_function::{ThreadSafeCallContext, ThreadsafeFunctionCallMode};
-use napi::{CallContext, Env, JsFunction, JsObject, JsString, JsUnknown};
+use napi::{CallContext, Env, JsFunction, JsObject, JsUnknown, Status, ValueType};
 use std::collections::HashMap;
 use std::ptr;
+use std::sync::mpsc::{channel, RecvTimeoutError};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 #[no_mangle]
 #[allow(non_snake_case, dead_code)]
@@ -35,10 +46,14 @@ pub extern "system" fn Java_io_github_markusjx_bridge_JavaFunctionCaller_callNod
     env: *mut sys::JNIEnv,
     _: sys::jobject,
     id: sys::jlong,
+    timeout_ms: sys::jlong,
+    non_blocking: sys::jboolean,
+    name: sys::jstring,
     method: sys::jobject,
     args: sys::jobjectArray,
 ) -> sys::jobject {
-    let res = unsafe { call_node_function(env, id, method, args) };
+    let res =
+        unsafe { call_node_function(env, id, timeout_ms, non_blocking != 0, name, method, args) };
     match res {
         Ok(obj) => match obj {
             Ok(obj) => obj,
@@ -62,98 +77,224 @@ pub extern "system" fn Java_io_github_markusjx_bridge_JavaFunctionCaller_callNod
     }
 }
 
+#[no_mangle]
+#[allow(non_snake_case, dead_code)]
+pub extern "system" fn Java_io_github_markusjx_bridge_JavaFunctionCaller_proxyCollected(
+    _env: *mut sys::JNIEnv,
+    _: sys::jobject,
+    id: sys::jlong,
+) {
+    remove_collected_proxy(id as _);
+}
+
 unsafe fn call_node_function(
     env: *mut sys::JNIEnv,
     id: sys::jlong,
+    timeout_ms: sys::jlong,
+    non_blocking: bool,
+    name: sys::jstring,
     method: sys::jobject,
     args: sys::jobjectArray,
 ) -> ResultType<Result<sys::jobject, JsError>> {
     let env = JavaEnv::from_raw(env);
     let method = LocalJavaObject::from_raw(method, &env, None);
-    let method_class = env.get_object_class(JavaObject::from(&method))?;
-    let get_name = method_class.get_object_method("getName", "()Ljava/lang/String;")?;
-
-    let java_name = get_name
-        .call(JavaObject::from(&method), &[])?
-        .ok_or("Class.getName() returned null")?;
-    let name = JavaString::try_from(java_name)?.to_string()?;
-
-    let proxies = get_proxies();
-    let daemon_proxies = get_daemon_proxies();
-    let methods = find_methods_by_id(id as _, &proxies, &daemon_proxies)?;
-    let method = methods
-        .get(&name)
-        .ok_or(format!("No method with the name '{}' exists", name))?;
+    // The name of the function implementing the method, which
+    // may be prefixed with the name of the declaring interface
+    let name = JavaString::from_raw(&env, name).to_string()?;
+    count_call(id as _, &name);
+    let parameter_types = get_method_parameters(&env, &method)?;
+    let return_type = get_method_return_type(&env, &method)?;
 
     let mut converted_args: Vec<JavaCallResult> = Vec::new();
     if !args.is_null() {
         let args = JavaObjectArray::from_raw(args, &env, None);
         for i in 0..args.len()? {
-            let arg = args.get(i)?;
-            converted_args.push(if let Some(arg) = arg {
-                JavaCallResult::try_from(JavaObject::from(arg))?
-            } else {
-                JavaCallResult::Null
+            converted_args.push(match (args.get(i)?, parameter_types.get(i as usize)) {
+                (Some(arg), Some(signature)) => convert_argument(&env, arg, signature)?,
+                (Some(arg), None) => JavaCallResult::try_from(JavaObject::from(arg))?,
+                (None, _) => JavaCallResult::Null,
             });
         }
     }
 
-    let (tx, rx) = channel::<JsCallResult>();
+    if non_blocking {
+        let handler = error_handler(id as _, name.clone());
+        let call = Arc::new(InterfaceCall::non_blocking(
+            converted_args,
+            return_type,
+            Box::new(handler.clone()),
+        ));
+
+        // Java doesn't wait for the call, so errors can't be thrown into java
+        if let Err(err) = dispatch_call(id as _, &name, call) {
+            handler(Err(err.to_string()));
+        }
 
-    method.call(
-        Ok(InterfaceCall::new(converted_args, tx)),
-        ThreadsafeFunctionCallMode::NonBlocking,
-    );
-    drop(methods);
-    drop(proxies);
-    drop(daemon_proxies);
+        return Ok(Ok(ptr::null_mut()));
+    }
+
+    let (tx, rx) = channel::<JsCallResult>();
+    let call = Arc::new(InterfaceCall::new(converted_args, return_type, tx));
+    // The call must not be kept alive here, as the result can
+    // never be received once all references to it are dropped
+    let pending = Arc::downgrade(&call);
+    dispatch_call(id as _, &name, call)?;
+
+    let res = if timeout_ms > 0 {
+        match rx.recv_timeout(Duration::from_millis(timeout_ms as _)) {
+            Ok(res) => Some(res),
+            Err(RecvTimeoutError::Timeout) if pending.upgrade().is_some_and(|c| c.cancel()) => {
+                throw_timeout(&env, &name, timeout_ms)?;
+                return Ok(Ok(ptr::null_mut()));
+            }
+            // The result was set right as the call timed out
+            Err(RecvTimeoutError::Timeout) => rx.recv().ok(),
+            Err(RecvTimeoutError::Disconnected) => None,
+        }
+    } else {
+        rx.recv().ok()
+    }
+    .ok_or(format!(
+        "The proxy method '{}' was dropped without returning a value",
+        name
+    ))??;
 
-    let res = futures::executor::block_on(rx)??;
     Ok(res.map(|o| {
         o.map(|g| g.into_return_value(&env))
             .unwrap_or(ptr::null_mut())
     }))
 }
 
+/// Pass a call to the method `name` of a proxy to the node thread.
+fn dispatch_call(id: usize, name: &str, call: Arc<InterfaceCall>) -> ResultType<()> {
+    if can_call_directly(id) {
+        // Java called the proxy on the node thread while it is waiting for
+        // the java call to return. Queueing the call through the threadsafe
+        // function would deadlock, so the function is called directly.
+        call_directly(id, name, call, true)
+    } else if let Err(call) = forward_call(id, name, call) {
+        call_threadsafe(id, name, call)
+    } else {
+        Ok(())
+    }
+}
+
+/// Create the handler passing the errors of non-blocking calls to
+/// the method `name` of a proxy to its `onError` callback, if any.
+fn error_handler(id: usize, name: String) -> impl Fn(JsCallResult) + Clone + Send + 'static {
+    let on_error = find_error_handler(id);
+    move |result| {
+        let message = match result {
+            Ok(Ok(_)) => return,
+            Ok(Err(err)) => err.message(),
+            Err(err) => err,
+        };
+
+        if let Some(on_error) = &on_error {
+            on_error.call(
+                (name.clone(), message),
+                ThreadsafeFunctionCallMode::NonBlocking,
+            );
+        } else {
+            debug!("The proxy method '{}' failed: {}", name, message);
+        }
+    }
+}
+
+/// Queue a call to the method `name` of a proxy
+/// on the node thread using its threadsafe function.
+pub(in crate::node::interface_proxy) fn call_threadsafe(
+    id: usize,
+    name: &str,
+    call: Arc<InterfaceCall>,
+) -> ResultType<()> {
+    let proxies = get_proxies();
+    let daemon_proxies = get_daemon_proxies();
+    let methods = find_methods_by_id(id, &proxies, &daemon_proxies)?;
+    let method = methods
+        .get(name)
+        .ok_or(format!("No method with the name '{}' exists", name))?;
+
+    queue_call(id, call, |call| {
+        match method.call(Ok(call), ThreadsafeFunctionCallMode::NonBlocking) {
+            Status::Ok => Ok(()),
+            // The environment is being torn down, the call would never return
+            Status::Closing => Err(torn_down_message(id).into()),
+            status => Err(format!("Failed to call the proxy method '{}': {}", name, status).into()),
+        }
+    })
+}
+
+/// Throw a `java.util.concurrent.TimeoutException` into java
+/// once a call to the proxy method `name` timed out.
+fn throw_timeout(env: &JavaEnv, name: &str, timeout_ms: i64) -> ResultType<()> {
+    let class = JavaClass::by_name("java/util/concurrent/TimeoutException", env)?;
+    let constructor = class.get_constructor("(Ljava/lang/String;)V")?;
+    let message = JavaString::from_string(
+        format!(
+            "The proxy method '{}' did not return within {} ms",
+            name, timeout_ms
+        ),
+        env,
+    )?;
+
+    let exception = constructor.new_instance(env, &[message.as_arg()])?;
+    env.throw(JavaObject::from(&exception));
+    Ok(())
+}
+
+/// Convert an argument passed to a proxy method by java. Java passes primitive
+/// values boxed, these are unboxed according to the declared parameter type.
+/// Objects keep their runtime type, as it is at least as specific as the
+/// declared type.
+fn convert_argument(
+    env: &JavaEnv,
+    arg: LocalJavaObject,
+    signature: &JavaType,
+) -> ResultType<JavaCallResult> {
+    Ok(match signature.type_enum() {
+        Type::Integer => JavaCallResult::Integer(env.object_to_int(&arg)?),
+        Type::Long => JavaCallResult::Long(env.object_to_long(&arg)?),
+        Type::Float => JavaCallResult::Float(env.object_to_float(&arg)?),
+        Type::Double => JavaCallResult::Double(env.object_to_double(&arg)?),
+        Type::Boolean => JavaCallResult::Boolean(env.object_to_boolean(&arg)?),
+        Type::Byte => JavaCallResult::Byte(env.object_to_byte(&arg)?),
+        Type::Short => JavaCallResult::Short(env.object_to_short(&arg)?),
+        Type::Character => JavaCallResult::Character(env.object_to_char(&arg)?),
+        _ => JavaCallResult::try_from(JavaObject::from(arg))?,
+    })
+}
+
 fn js_callback(
     ctx: &CallContext,
+    call: &InterfaceCall,
     vm: &JavaVM,
+    config: &Config,
 ) -> ResultType<Result<Option<GlobalJavaObject>, JsError>> {
     let err = ctx.get::<JsUnknown>(0)?;
 
     if err.is_error()? {
-        let obj = err.coerce_to_object()?;
-        let message = obj
-            .get_named_property::<JsString>("message")?
-            .into_utf16()?
-            .as_str()?;
-
-        let mut stack = if obj.has_named_property("stack")? {
-            obj.get_named_property::<JsString>("stack")
-                .ok()
-                .and_then(|s| s.into_utf16().ok())
-                .and_then(|s| s.as_str().ok())
-                .map(|stack| {
-                    stack
-                        .split('\n')
-                        .map(|s| s.to_string())
-                        .collect::<Vec<String>>()
-                })
-                .map(|mut stack| {
-                    stack.remove(0);
-                    stack
-                })
-        } else {
-            None
-        }
-        .unwrap_or(vec![]);
-
-        JsError::push_stack(&mut stack, function!(), file!(), line!());
-        Ok(Err(JsError::new(message, stack)))
+        Ok(Err(JsError::from_js(err)?))
+    } else if call.return_type.type_enum() == Type::Void {
+        Ok(Ok(None))
     } else {
         let env = vm.attach_thread()?;
         let result = ctx.get::<JsUnknown>(1)?;
-        let converted = JavaType::object().convert_to_java_object(&env, ctx.env, result)?;
+        if call.return_type.is_primitive()
+            && matches!(result.get_type()?, ValueType::Null | ValueType::Undefined)
+        {
+            return Err(format!(
+                "Expected the proxy method to return a value of type {}",
+                call.return_type
+            )
+            .into());
+        }
+
+        // Java converts the returned object to the declared return type,
+        // so primitive values must be returned as their boxed type
+        let converted = call
+            .return_type
+            .convert_to_java_object(&env, ctx.env, result, config)?;
 
         Ok(Ok(if let Some(converted) = converted {
             Some(converted.into_global()?)
@@ -163,13 +304,49 @@ fn js_callback(
     }
 }
 
-#[napi]
+/// Create the arguments passed to the javascript function implementing
+/// a proxy method: the callback receiving the result of the call,
+/// followed by the converted arguments of the call.
+pub(in crate::node::interface_proxy) fn create_call_args(
+    env: &Env,
+    call: Arc<InterfaceCall>,
+    vm: &JavaVM,
+    config: &Config,
+) -> napi::Result<Vec<JsUnknown>> {
+    let callback_call = call.clone();
+    let callback_vm = vm.clone();
+    let callback_config = config.clone();
+    let mut res = vec![env
+        .create_function_from_closure("callback", move |ctx| {
+            callback_call
+                .set_result(
+                    js_callback(&ctx, &callback_call, &callback_vm, &callback_config)
+                        .map_err(|e| e.to_string()),
+                )
+                .map_napi_err(Some(*ctx.env))?;
+            ctx.env.get_undefined()
+        })?
+        .into_unknown()];
+
+    let j_env = vm.attach_thread().map_napi_err(Some(*env))?;
+    for value in call.args.iter() {
+        res.push(
+            value
+                .to_napi_value(&j_env, env, config)
+                .map_napi_err(Some(*env))?,
+        );
+    }
+
+    Ok(res)
+}
+
+#[napi(custom_finalize)]
 pub struct JavaInterfaceProxy {
     id: usize,
     methods: MethodsType,
     proxy_instance: Option<GlobalJavaObject>,
     function_caller_instance: FunctionCaller,
-    options: InterfaceProxyOptions,
+    keep_as_daemon: bool,
 }
 
 #[napi]
@@ -177,10 +354,30 @@ impl JavaInterfaceProxy {
     pub fn new(
         vm: JavaVM,
         env: Env,
-        classname: String,
+        interfaces: Vec<String>,
         methods: HashMap<String, JsFunction>,
-        options: InterfaceProxyOptions,
+        mut options: InterfaceProxyOptions,
     ) -> ResultType<Self> {
+        if interfaces.is_empty() {
+            return Err("At least one interface must be implemented".into());
+        }
+
+        let non_blocking_methods = match options.non_blocking_void.take() {
+            Some(Either::A(true)) => methods.keys().cloned().collect(),
+            Some(Either::B(names)) => names,
+            Some(Either::A(false)) | None => vec![],
+        };
+        if let Some(name) = non_blocking_methods
+            .iter()
+            .find(|name| !methods.contains_key(*name))
+        {
+            return Err(format!(
+                "The non-blocking method '{}' is not implemented by the proxy",
+                name
+            )
+            .into());
+        }
+
         let j_env = vm.attach_thread()?;
 
         let mut proxies = get_proxies();
@@ -198,13 +395,22 @@ impl JavaInterfaceProxy {
             "io.github.markusjx.bridge.JavaFunctionCaller".into(),
             &j_env,
         )?;
-        let constructor = java_class.get_constructor("([Ljava/lang/String;J)V")?;
+        let constructor =
+            java_class.get_constructor("([Ljava/lang/String;JJ[Ljava/lang/String;)V")?;
+        let mut java_non_blocking_methods =
+            JavaObjectArray::new(&string, non_blocking_methods.len())?;
+        for (i, name) in non_blocking_methods.into_iter().enumerate() {
+            let str = JavaString::from_string(name, &j_env)?;
+            java_non_blocking_methods.set(i as _, Some(JavaObject::from(str)))?;
+        }
 
         let instance = constructor.new_instance(
             &j_env,
             &[
                 implemented_methods.as_arg(),
                 JavaLong::new(id as _).as_arg(),
+                JavaLong::new(options.timeout_ms.unwrap_or(0) as _).as_arg(),
+                java_non_blocking_methods.as_arg(),
             ],
         )?;
 
@@ -212,9 +418,14 @@ impl JavaInterfaceProxy {
         let new_proxy_instance = proxy.get_static_object_method("newProxyInstance", "(Ljava/lang/ClassLoader;[Ljava/lang/Class;Ljava/lang/reflect/InvocationHandler;)Ljava/lang/Object;")?;
 
         let class = j_env.get_java_lang_class()?;
-        let proxied_class = JavaClass::by_java_name(classname, &j_env)?;
-        let mut classes = JavaObjectArray::new(&class, 1)?;
-        classes.set(0, Some(JavaObject::from(&proxied_class)))?;
+        let proxied_classes = interfaces
+            .iter()
+            .map(|interface| JavaClass::by_java_name(interface.clone(), &j_env))
+            .collect::<ResultType<Vec<_>>>()?;
+        let mut classes = JavaObjectArray::new(&class, proxied_classes.len())?;
+        for (i, proxied_class) in proxied_classes.iter().enumerate() {
+            classes.set(i as _, Some(JavaObject::from(proxied_class)))?;
+        }
 
         let proxy_instance = new_proxy_instance
             .call(&[
@@ -224,58 +435,101 @@ impl JavaInterfaceProxy {
             ])?
             .ok_or("java.lang.reflect.Proxy.newProxyInstance returned null".to_string())?;
 
+        // Remove the proxy once java no longer references it
+        let register_cleaner =
+            java_class.get_void_method("registerCleaner", "(Ljava/lang/Object;)V")?;
+        register_cleaner.call(JavaObject::from(&instance), &[proxy_instance.as_arg()])?;
+
         let global_proxy_instance = GlobalJavaObject::try_from(proxy_instance)?;
         let global_function_caller_instance = GlobalJavaObject::try_from(instance)?;
 
+        let config = Config::get().clone();
         let mut converted_methods = HashMap::new();
-        for (name, method) in methods.into_iter() {
+        for (name, method) in methods.iter() {
             let vm_copy = vm.clone();
+            let config = config.clone();
             converted_methods.insert(
-                name,
+                name.clone(),
                 env.create_threadsafe_function(
-                    &method,
+                    method,
                     0,
-                    move |ctx: ThreadSafeCallContext<InterfaceCall>| {
-                        let args = ctx.value.args.clone();
-                        let callback_vm = vm_copy.clone();
-                        let mut res = vec![ctx
-                            .env
-                            .create_function_from_closure("callback", move |ctx1| {
-                                ctx.value
-                                    .set_result(
-                                        js_callback(&ctx1, &callback_vm).map_err(|e| e.to_string()),
-                                    )
-                                    .map_napi_err(Some(ctx.env))?;
-                                ctx1.env.get_undefined()
-                            })?
-                            .into_unknown()];
-                        for value in args {
-                            let env = vm_copy.attach_thread().map_napi_err(Some(ctx.env))?;
-                            res.push(
-                                value
-                                    .to_napi_value(&env, &ctx.env)
-                                    .map_napi_err(Some(ctx.env))?,
-                            );
-                        }
-
-                        Ok(res)
+                    move |ctx: ThreadSafeCallContext<Arc<InterfaceCall>>| {
+                        create_call_args(&ctx.env, ctx.value, &vm_copy, &config)
                     },
                 )?,
             );
         }
 
+        let creation_stack = if config.capture_proxy_stacks.unwrap_or_default() {
+            Some(capture_stack(&env)?)
+        } else {
+            None
+        };
+        let info = ProxyInfo::new(
+            interfaces,
+            methods.keys().cloned().collect(),
+            options.keep_as_daemon.unwrap_or(false),
+            creation_stack,
+            global_function_caller_instance.clone(),
+        );
+
+        let on_error = match options.on_error.take() {
+            Some(on_error) => {
+                let mut on_error: ErrorHandler = on_error.create_threadsafe_function(
+                    0,
+                    |ctx: ThreadSafeCallContext<(String, String)>| {
+                        let (name, message) = ctx.value;
+                        Ok(vec![
+                            ctx.env
+                                .create_error(napi::Error::from_reason(message))?
+                                .into_unknown(),
+                            ctx.env.create_string_from_std(name)?.into_unknown(),
+                        ])
+                    },
+                )?;
+
+                // Errors are only reported while the proxy is called,
+                // so the handler must not keep the process alive
+                on_error.unref(&env)?;
+                Some(on_error)
+            }
+            None => None,
+        };
+
+        register_functions(&env, id, &methods, &vm, &config)?;
         let converted_methods = Arc::new(Mutex::new(converted_methods));
-        proxies.insert(id, converted_methods.clone());
+        proxies.insert(
+            id,
+            ProxyEntry {
+                methods: converted_methods.clone(),
+                info: Arc::new(info),
+                on_error,
+            },
+        );
 
         Ok(Self {
             id,
             methods: converted_methods,
             function_caller_instance: FunctionCaller::new(global_function_caller_instance),
             proxy_instance: Some(global_proxy_instance),
-            options,
+            keep_as_daemon: options.keep_as_daemon.unwrap_or(false),
         })
     }
 
+    /// Get the java proxy instance, if this proxy hasn't been destroyed yet.
+    pub fn java_proxy(&self) -> Option<&GlobalJavaObject> {
+        self.proxy_instance.as_ref()
+    }
+
+    /// Allow the node process to exit while this proxy is alive.
+    pub fn unref(&self, env: &Env) -> napi::Result<()> {
+        for method in self.methods.lock().unwrap().values_mut() {
+            method.unref(env)?;
+        }
+
+        Ok(())
+    }
+
     #[napi(getter)]
     pub fn proxy(&self, env: Env) -> napi::Result<Option<JsObject>> {
         self.proxy_instance.as_ref().map_or(Ok(None), |proxy| {
@@ -297,8 +551,14 @@ impl JavaInterfaceProxy {
             return Err(NapiError::from("This instance is already destroyed").into());
         }
 
-        let keep_as_daemon =
-            self.options.keep_as_daemon.unwrap_or(false) && !force.unwrap_or(false);
+        // The proxy may have been destroyed by id using `proxies.destroy`
+        if !get_proxies().contains_key(&self.id) {
+            self.function_caller_instance.release();
+            self.proxy_instance.take();
+            return Err(NapiError::from("This instance is already destroyed").into());
+        }
+
+        let keep_as_daemon = self.keep_as_daemon && !force.unwrap_or(false);
         if !keep_as_daemon {
             self.function_caller_instance.destroy(env)?;
         }
@@ -315,6 +575,7 @@ impl JavaInterfaceProxy {
 
         self.proxy_instance.take();
         if !keep_as_daemon {
+            unregister_functions(self.id);
             methods.clear();
         }
 
@@ -322,8 +583,41 @@ impl JavaInterfaceProxy {
     }
 }
 
+impl JavaInterfaceProxy {
+    /// Leave the lifetime of the proxy to java once it is no longer
+    /// referenced by javascript. The proxy can still be called by java
+    /// and is removed once its java instance is garbage collected.
+    /// Does nothing if the proxy was already destroyed.
+    fn release(&mut self, env: Option<Env>) {
+        if self.proxy_instance.is_none() {
+            return;
+        }
+
+        if self.keep_as_daemon {
+            self.reset_inner(Some(false), env).ok();
+            return;
+        }
+
+        // Proxies no longer referenced by javascript
+        // should not keep the process alive
+        if let Some(env) = env {
+            self.unref(&env).ok();
+        }
+
+        self.proxy_instance.take();
+        self.function_caller_instance.release();
+    }
+}
+
+impl ObjectFinalize for JavaInterfaceProxy {
+    fn finalize(mut self, env: Env) -> napi::Result<()> {
+        self.release(Some(env));
+        Ok(())
+    }
+}
+
 impl Drop for JavaInterfaceProxy {
     fn drop(&mut self) {
-        self.reset_inner(Some(false), None).ok();
+        self.release(None);
     }
 }
@@ -5,7 +5,9 @@ use java_rs::objects::args::AsJavaArg;
 use java_rs::objects::array::JavaObjectArray;
 use java_rs::objects::class::JavaClass;
 use java_rs::objects::java_object::JavaObject;
+use java_rs::objects::object::GlobalJavaObject;
 use java_rs::objects::string::JavaString;
+use napi::{JsString, JsUnknown};
 
 pub struct JsError {
     message: String,
@@ -26,8 +28,58 @@ impl JsError {
         stack.insert(0, format!("\tat {} ({}:{})", method, file, line));
     }
 
-    pub fn throw(&self, env: &JavaEnv) -> ResultType<()> {
-        let utils = JavaClass::by_name("io/github/markusjx/bridge/Util", env)?;
+    /// Convert a javascript value to a [`JsError`].
+    /// The message and stack trace are taken from error objects,
+    /// any other value is converted to a string.
+    pub fn from_js(value: JsUnknown) -> napi::Result<Self> {
+        if !value.is_error()? {
+            let message = value.coerce_to_string()?.into_utf16()?.as_str()?;
+            return Ok(Self::new(message, vec![]));
+        }
+
+        let obj = value.coerce_to_object()?;
+        let message = obj
+            .get_named_property::<JsString>("message")?
+            .into_utf16()?
+            .as_str()?;
+
+        let mut stack = if obj.has_named_property("stack")? {
+            obj.get_named_property::<JsString>("stack")
+                .ok()
+                .and_then(|s| s.into_utf16().ok())
+                .and_then(|s| s.as_str().ok())
+                .map(|stack| {
+                    stack
+                        .split('\n')
+                        .map(|s| s.to_string())
+                        .collect::<Vec<String>>()
+                })
+                .map(|mut stack| {
+                    stack.remove(0);
+                    stack
+                })
+        } else {
+            None
+        }
+        .unwrap_or(vec![]);
+
+        Self::push_stack(&mut stack, function!(), file!(), line!());
+        Ok(Self::new(message, stack))
+    }
+
+    /// Create a java exception from this error.
+    /// The util class is loaded using the class loader of the vm,
+    /// `env` must therefore be obtained using `JavaVM::attach_thread`.
+    pub fn to_throwable(&self, env: &JavaEnv) -> ResultType<GlobalJavaObject> {
+        let utils = JavaClass::by_java_name("io.github.markusjx.bridge.Util".into(), env)?;
+        self.exception_from_js_error(&utils, env)
+    }
+
+    fn exception_from_js_error(
+        &self,
+        utils: &JavaClass,
+        env: &JavaEnv,
+    ) -> ResultType<GlobalJavaObject> {
         let exception_from_js_error = utils.get_static_object_method(
             "exceptionFromJsError",
             "(Ljava/lang/String;[Ljava/lang/String;)Ljava/lang/Exception;",
@@ -57,7 +109,14 @@ impl JsError {
             .ok_or(
                 "io/github/markusjx/bridge/Util.exceptionFromJsError returned null".to_string(),
             )?;
-        env.throw(JavaObject::from(exception));
+
+        GlobalJavaObject::try_from(exception)
+    }
+
+    pub fn throw(&self, env: &JavaEnv) -> ResultType<()> {
+        let utils = JavaClass::by_name("io/github/markusjx/bridge/Util", env)?;
+        let exception = self.exception_from_js_error(&utils, env)?;
+        env.throw(JavaObject::from(&exception));
         Ok(())
     }
 
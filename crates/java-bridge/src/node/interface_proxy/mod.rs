@@ -1,7 +1,11 @@
 mod function_caller;
+pub mod function_proxy;
 mod interface_call;
 pub mod interface_proxy_options;
 pub mod java_interface_proxy;
-mod js_error;
+pub mod js_error;
+mod pending_calls;
 pub mod proxies;
+pub mod proxy_info;
+pub mod reentrant_calls;
 mod types;
@@ -0,0 +1,78 @@
+use crate::node::interface_proxy::interface_call::InterfaceCall;
+use crate::node::util::helpers::ResultType;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
+
+/// The minimum number of tracked calls before completed calls are removed.
+const MIN_PRUNE_AT: usize = 16;
+
+/// The calls of a proxy queued on its node thread.
+#[derive(Default)]
+struct PendingCalls {
+    /// Whether the node environment of the proxy was torn down.
+    torn_down: bool,
+    calls: Vec<Weak<InterfaceCall>>,
+    /// The number of tracked calls at which completed calls are removed.
+    /// Grows with the number of queued calls, as non-blocking calls may
+    /// be queued much faster than node processes them.
+    prune_at: usize,
+}
+
+lazy_static! {
+    /// The queued calls of all proxies, by proxy id. Node drops calls still
+    /// queued when its environment is torn down without freeing them, so
+    /// these must be failed explicitly, otherwise java would wait forever.
+    static ref PENDING_CALLS: Mutex<HashMap<usize, PendingCalls>> = Mutex::new(HashMap::new());
+}
+
+/// Queue a call to the proxy with the given id using `queue`, keeping track
+/// of it until it has completed. Fails without queueing the call if the
+/// node environment of the proxy was already torn down.
+pub fn queue_call(
+    id: usize,
+    call: Arc<InterfaceCall>,
+    queue: impl FnOnce(Arc<InterfaceCall>) -> ResultType<()>,
+) -> ResultType<()> {
+    let mut pending_calls = PENDING_CALLS.lock().unwrap();
+    let pending = pending_calls.entry(id).or_default();
+    if pending.torn_down {
+        return Err(torn_down_message(id).into());
+    }
+
+    if pending.calls.len() >= pending.prune_at {
+        pending.calls.retain(|call| call.strong_count() > 0);
+        pending.prune_at = (pending.calls.len() * 2).max(MIN_PRUNE_AT);
+    }
+
+    pending.calls.push(Arc::downgrade(&call));
+    queue(call)
+}
+
+/// Fail all queued calls to the proxies with the given ids, as
+/// well as all calls made to them after this. Called once the
+/// node environment the proxies were created in is torn down.
+pub fn fail_pending_calls(ids: impl IntoIterator<Item = usize>) {
+    let mut pending_calls = PENDING_CALLS.lock().unwrap();
+    for id in ids {
+        let pending = pending_calls.entry(id).or_default();
+        pending.torn_down = true;
+
+        for call in pending.calls.drain(..).filter_map(|call| call.upgrade()) {
+            call.abandon(torn_down_message(id));
+        }
+    }
+}
+
+/// Stop keeping track of the calls to a proxy once it was destroyed.
+pub fn remove_pending_calls(id: usize) {
+    PENDING_CALLS.lock().unwrap().remove(&id);
+}
+
+/// The error message of calls to a proxy whose node environment was torn down.
+pub fn torn_down_message(id: usize) -> String {
+    format!(
+        "The node environment of the proxy with the id {} has been torn down",
+        id
+    )
+}
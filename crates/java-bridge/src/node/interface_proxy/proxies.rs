@@ -1,5 +1,8 @@
+use crate::node::helpers::napi_error::NapiError;
 use crate::node::interface_proxy::function_caller::FunctionCaller;
-use crate::node::interface_proxy::types::{MethodMap, MethodsType, ProxiesType};
+use crate::node::interface_proxy::proxy_info::InterfaceProxyInfo;
+use crate::node::interface_proxy::reentrant_calls::unregister_functions;
+use crate::node::interface_proxy::types::{ErrorHandler, MethodMap, ProxiesType, ProxyEntry};
 use crate::node::util::helpers::ResultType;
 use lazy_static::lazy_static;
 use napi::Env;
@@ -7,7 +10,7 @@ use rand::RngCore;
 use std::collections::HashMap;
 use std::sync::{Mutex, MutexGuard};
 
-type DaemonProxiesType = HashMap<usize, (MethodsType, FunctionCaller)>;
+type DaemonProxiesType = HashMap<usize, (ProxyEntry, FunctionCaller)>;
 
 lazy_static! {
     static ref PROXIES: Mutex<ProxiesType> = Mutex::new(HashMap::new());
@@ -42,12 +45,34 @@ pub(in crate::node::interface_proxy) fn find_methods_by_id(
     proxies: &MutexGuard<ProxiesType>,
     daemon_proxies: &MutexGuard<DaemonProxiesType>,
 ) -> ResultType<MethodMap> {
-    if let Some(methods) = proxies.get(&id) {
-        Ok(methods.lock().unwrap().clone())
-    } else if let Some((methods, _)) = daemon_proxies.get(&id) {
-        Ok(methods.lock().unwrap().clone())
-    } else {
-        Err(format!("No proxy with the id {} exists", id).into())
+    find_entry_by_id(id, proxies, daemon_proxies)
+        .map(|entry| entry.methods.lock().unwrap().clone())
+        .ok_or(format!("No proxy with the id {} exists", id).into())
+}
+
+fn find_entry_by_id<'a>(
+    id: usize,
+    proxies: &'a MutexGuard<ProxiesType>,
+    daemon_proxies: &'a MutexGuard<DaemonProxiesType>,
+) -> Option<&'a ProxyEntry> {
+    proxies
+        .get(&id)
+        .or_else(|| daemon_proxies.get(&id).map(|(entry, _)| entry))
+}
+
+/// Get the handler receiving the errors of non-blocking calls to a proxy, if any.
+pub(in crate::node::interface_proxy) fn find_error_handler(id: usize) -> Option<ErrorHandler> {
+    let proxies = get_proxies();
+    let daemon_proxies = get_daemon_proxies();
+    find_entry_by_id(id, &proxies, &daemon_proxies).and_then(|entry| entry.on_error.clone())
+}
+
+/// Count a call to the method `name` of a proxy by java.
+pub(in crate::node::interface_proxy) fn count_call(id: usize, name: &str) {
+    let proxies = get_proxies();
+    let daemon_proxies = get_daemon_proxies();
+    if let Some(entry) = find_entry_by_id(id, &proxies, &daemon_proxies) {
+        entry.info.count_call(name);
     }
 }
 
@@ -65,13 +90,27 @@ pub(in crate::node::interface_proxy) fn remove_proxy(
 
     if let Some(caller) = function_caller {
         if caller.is_alive() {
-            if let Some(methods) = removed {
-                daemon_proxies.insert(id, (methods, caller));
+            if let Some(entry) = removed {
+                daemon_proxies.insert(id, (entry, caller));
             }
         }
     }
 }
 
+/// Remove a proxy once its java instance was garbage collected. Only
+/// proxies no longer referenced by javascript are collected by java.
+pub(in crate::node::interface_proxy) fn remove_collected_proxy(id: usize) {
+    let removed = {
+        let mut proxies = get_proxies();
+        let mut daemon_proxies = get_daemon_proxies();
+        (proxies.remove(&id), daemon_proxies.remove(&id))
+    };
+
+    // The proxy is destroyed once the locks are released
+    drop(removed);
+    unregister_functions(id);
+}
+
 pub fn interface_proxy_exists() -> bool {
     !PROXIES.lock().unwrap().is_empty() || !DAEMON_PROXIES.lock().unwrap().is_empty()
 }
@@ -81,11 +120,51 @@ pub fn interface_proxy_exists() -> bool {
 #[allow(unused)]
 pub fn clear_daemon_proxies(env: Env) -> napi::Result<()> {
     let mut proxies = DAEMON_PROXIES.lock().unwrap();
-    for (_, (methods, function_caller)) in proxies.iter_mut() {
+    for (id, (entry, function_caller)) in proxies.iter_mut() {
+        unregister_functions(*id);
         function_caller.destroy(Some(env))?;
-        methods.lock().unwrap().clear();
+        entry.methods.lock().unwrap().clear();
     }
 
     proxies.clear();
     Ok(())
 }
+
+/// Get information about all registered proxies, sorted by id.
+pub(in crate::node::interface_proxy) fn list_proxies() -> Vec<InterfaceProxyInfo> {
+    let proxies = get_proxies();
+    let daemon_proxies = get_daemon_proxies();
+
+    let mut res = proxies
+        .iter()
+        .map(|(id, entry)| entry.info.to_js(*id, false))
+        .chain(
+            daemon_proxies
+                .iter()
+                .map(|(id, (entry, _))| entry.info.to_js(*id, true)),
+        )
+        .collect::<Vec<_>>();
+    res.sort_by_key(|info| info.id);
+    res
+}
+
+/// Destroy a proxy by id, even if it is still referenced by
+/// javascript or java. Calls to the proxy by java will fail.
+pub(in crate::node::interface_proxy) fn destroy_proxy(id: usize, env: Env) -> napi::Result<()> {
+    let entry = {
+        let mut proxies = get_proxies();
+        let mut daemon_proxies = get_daemon_proxies();
+        proxies
+            .remove(&id)
+            .or_else(|| daemon_proxies.remove(&id).map(|(entry, _)| entry))
+    }
+    .ok_or(NapiError::from(format!(
+        "No proxy with the id {} exists",
+        id
+    )))?;
+
+    unregister_functions(id);
+    FunctionCaller::new(entry.info.function_caller.clone()).destroy(Some(env))?;
+    entry.methods.lock().unwrap().clear();
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use java_rs::objects::object::GlobalJavaObject;
+use napi::{Env, JsString};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+
+/// Information about a live interface proxy,
+/// returned by `proxies.list`.
+///
+/// @since 2.9.0
+#[napi(object)]
+pub struct InterfaceProxyInfo {
+    /// The id of the proxy.
+    pub id: u32,
+    /// The names of the interfaces implemented by the proxy.
+    pub interfaces: Vec<String>,
+    /// The names of the methods implemented by the proxy.
+    pub methods: Vec<String>,
+    /// Whether the proxy was created with the `keepAsDaemon` option.
+    pub keep_as_daemon: bool,
+    /// Whether the proxy is kept alive as a daemon proxy,
+    /// i.e. it has been reset or garbage collected and is
+    /// kept alive until java no longer references it.
+    pub daemon: bool,
+    /// The javascript stack trace at the creation of the proxy.
+    /// Only recorded if the `captureProxyStacks` option is set.
+    pub creation_stack: Option<String>,
+    /// The number of times java called each method of the proxy.
+    pub calls: HashMap<String, i64>,
+}
+
+/// The information kept about a proxy while it is registered.
+pub struct ProxyInfo {
+    interfaces: Vec<String>,
+    keep_as_daemon: bool,
+    creation_stack: Option<String>,
+    calls: HashMap<String, AtomicI64>,
+    /// The java function caller of the proxy,
+    /// allowing the proxy to be destroyed by id.
+    pub function_caller: GlobalJavaObject,
+}
+
+impl ProxyInfo {
+    pub fn new(
+        interfaces: Vec<String>,
+        methods: Vec<String>,
+        keep_as_daemon: bool,
+        creation_stack: Option<String>,
+        function_caller: GlobalJavaObject,
+    ) -> Self {
+        Self {
+            interfaces,
+            keep_as_daemon,
+            creation_stack,
+            calls: methods
+                .into_iter()
+                .map(|method| (method, AtomicI64::new(0)))
+                .collect(),
+            function_caller,
+        }
+    }
+
+    /// Count a call to the method `name` by java.
+    pub fn count_call(&self, name: &str) {
+        if let Some(calls) = self.calls.get(name) {
+            calls.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn to_js(&self, id: usize, daemon: bool) -> InterfaceProxyInfo {
+        let mut methods = self.calls.keys().cloned().collect::<Vec<_>>();
+        methods.sort();
+
+        InterfaceProxyInfo {
+            id: id as _,
+            interfaces: self.interfaces.clone(),
+            methods,
+            keep_as_daemon: self.keep_as_daemon,
+            daemon,
+            creation_stack: self.creation_stack.clone(),
+            calls: self
+                .calls
+                .iter()
+                .map(|(name, calls)| (name.clone(), calls.load(Ordering::Relaxed)))
+                .collect(),
+        }
+    }
+}
+
+/// Capture the current javascript stack trace.
+pub fn capture_stack(env: &Env) -> napi::Result<String> {
+    let error = napi::JsError::from(napi::Error::from_reason("Interface proxy created"))
+        .into_unknown(*env)
+        .coerce_to_object()?;
+    let stack: JsString = error.get_named_property("stack")?;
+
+    stack.into_utf8()?.into_owned()
+}
+
+/// A namespace containing functions for inspecting
+/// and destroying live interface proxies.
+///
+/// @since 2.9.0
+#[napi]
+pub mod proxies {
+    use crate::node::interface_proxy::proxies::{destroy_proxy, list_proxies};
+    use crate::node::interface_proxy::proxy_info::InterfaceProxyInfo;
+    use napi::Env;
+
+    /// List all live interface proxies, including daemon proxies
+    /// and proxies which are only referenced by java.
+    #[napi]
+    #[allow(unused)]
+    pub fn list() -> Vec<InterfaceProxyInfo> {
+        list_proxies()
+    }
+
+    /// Destroy the interface proxy with the given id, even if
+    /// it is still referenced. Any call to the proxy by java
+    /// will throw an error afterwards.
+    #[napi]
+    #[allow(unused)]
+    pub fn destroy(env: Env, id: u32) -> napi::Result<()> {
+        destroy_proxy(id as _, env)
+    }
+}
@@ -0,0 +1,344 @@
+use crate::node::config::Config;
+use crate::node::helpers::napi_error::NapiError;
+use crate::node::interface_proxy::interface_call::InterfaceCall;
+use crate::node::interface_proxy::java_interface_proxy::{call_threadsafe, create_call_args};
+use crate::node::interface_proxy::js_error::JsError;
+use crate::node::interface_proxy::pending_calls::{fail_pending_calls, remove_pending_calls};
+use crate::node::util::helpers::ResultType;
+use java_rs::java_vm::JavaVM;
+use lazy_static::lazy_static;
+use napi::threadsafe_function::{
+    ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+};
+use napi::{Env, JsFunction, JsUnknown, Ref};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::mem;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{channel, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// The javascript functions implementing the methods of a proxy.
+struct ProxyFunctions {
+    env: Env,
+    vm: JavaVM,
+    config: Config,
+    functions: HashMap<String, Ref<()>>,
+}
+
+impl ProxyFunctions {
+    fn unref(mut self) {
+        for function in self.functions.values_mut() {
+            function.unref(self.env).ok();
+        }
+    }
+}
+
+impl Drop for ProxyFunctions {
+    fn drop(&mut self) {
+        // Functions which weren't released using unref are leaked, as
+        // the environment may already be gone if the thread is exiting
+        self.functions
+            .drain()
+            .for_each(|(_, function)| mem::forget(function));
+    }
+}
+
+/// A proxy call passed to a node thread waiting for a java call to return.
+struct ForwardedCall {
+    id: usize,
+    name: String,
+    call: Arc<InterfaceCall>,
+    /// Whether the call was made by the thread running the awaited java call,
+    /// in which case the node thread can't return until the call returns.
+    must_return: bool,
+}
+
+thread_local! {
+    /// The functions of all proxies created on this thread, by proxy id.
+    /// Only the node thread creating a proxy may call its functions directly.
+    static PROXY_FUNCTIONS: RefCell<HashMap<usize, ProxyFunctions>> =
+        RefCell::new(HashMap::new());
+    /// Releases the functions of proxies created on this thread if they are
+    /// removed by another thread. Created along with the environment
+    /// cleanup hook of this thread once the first proxy is created.
+    static RELEASE_FUNCTIONS: RefCell<Option<ThreadsafeFunction<usize>>> =
+        const { RefCell::new(None) };
+    /// Whether this thread runs a java call awaited by a node thread.
+    static RUNS_AWAITED_CALL: Cell<bool> = const { Cell::new(false) };
+}
+
+lazy_static! {
+    /// The threadsafe functions releasing the functions of
+    /// a proxy on the thread which created it, by proxy id.
+    static ref RELEASERS: Mutex<HashMap<usize, ThreadsafeFunction<usize>>> =
+        Mutex::new(HashMap::new());
+}
+
+/// Get the threadsafe function releasing the functions of proxies created on
+/// this thread. Once called for the first time, this also registers a hook
+/// failing the calls to these proxies once the node environment is torn down,
+/// as they will never be called by node.
+fn get_release_functions(env: &Env) -> napi::Result<ThreadsafeFunction<usize>> {
+    if let Some(release) = RELEASE_FUNCTIONS.with_borrow(|release| release.clone()) {
+        return Ok(release);
+    }
+
+    let mut env = *env;
+    env.add_env_cleanup_hook((), |_| {
+        let ids = PROXY_FUNCTIONS
+            .try_with(|proxies| proxies.borrow().keys().copied().collect::<Vec<_>>())
+            .unwrap_or_default();
+        fail_pending_calls(ids);
+    })?;
+
+    let noop =
+        env.create_function_from_closure("releaseFunctions", |ctx| ctx.env.get_undefined())?;
+    let mut release =
+        env.create_threadsafe_function(&noop, 0, |ctx: ThreadSafeCallContext<usize>| {
+            release_local_functions(ctx.value);
+            Ok(Vec::<JsUnknown>::new())
+        })?;
+
+    // Releasing functions must not keep the process alive
+    release.unref(&env)?;
+    RELEASE_FUNCTIONS.set(Some(release.clone()));
+    Ok(release)
+}
+
+/// Store the functions of a proxy, allowing them to be called directly
+/// if java calls the proxy while this thread is waiting for java.
+pub fn register_functions(
+    env: &Env,
+    id: usize,
+    methods: &HashMap<String, JsFunction>,
+    vm: &JavaVM,
+    config: &Config,
+) -> napi::Result<()> {
+    let functions = methods
+        .iter()
+        .map(|(name, function)| Ok((name.clone(), env.create_reference(function)?)))
+        .collect::<napi::Result<HashMap<_, _>>>()?;
+    let release = get_release_functions(env)?;
+    RELEASERS.lock().unwrap().insert(id, release);
+
+    let existing = PROXY_FUNCTIONS.with_borrow_mut(|proxies| {
+        proxies.insert(
+            id,
+            ProxyFunctions {
+                env: *env,
+                vm: vm.clone(),
+                config: config.clone(),
+                functions,
+            },
+        )
+    });
+
+    if let Some(existing) = existing {
+        existing.unref();
+    }
+
+    Ok(())
+}
+
+/// Release the functions of a proxy. If called on a thread other than
+/// the one creating the proxy, the functions are released by that
+/// thread the next time it runs its event loop.
+pub fn unregister_functions(id: usize) {
+    let release = RELEASERS.lock().unwrap().remove(&id);
+    if !release_local_functions(id) {
+        if let Some(release) = release {
+            release.call(Ok(id), ThreadsafeFunctionCallMode::NonBlocking);
+        }
+    }
+}
+
+/// Release the functions of a proxy created on this thread.
+/// Returns `false` if the proxy was created on another thread.
+fn release_local_functions(id: usize) -> bool {
+    match PROXY_FUNCTIONS.with_borrow_mut(|proxies| proxies.remove(&id)) {
+        Some(functions) => {
+            functions.unref();
+            remove_pending_calls(id);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Check if the functions of the proxy with the
+/// given id can be called directly on this thread.
+pub fn can_call_directly(id: usize) -> bool {
+    PROXY_FUNCTIONS.with_borrow(|proxies| proxies.contains_key(&id))
+}
+
+/// Call the function implementing the method `name` of a proxy on this
+/// thread, without going through its threadsafe function. This must only
+/// be called if [`can_call_directly`] returned `true`. If `must_return` is
+/// set, the java thread calling the proxy is blocking this thread, so the
+/// function must call its callback before returning, otherwise the call
+/// fails. Otherwise, the result may also be passed to the callback once
+/// this thread runs its event loop again.
+pub fn call_directly(
+    id: usize,
+    name: &str,
+    call: Arc<InterfaceCall>,
+    must_return: bool,
+) -> ResultType<()> {
+    let (env, vm, config, function) = PROXY_FUNCTIONS.with_borrow(|proxies| {
+        let proxy = proxies
+            .get(&id)
+            .ok_or(format!("No proxy with the id {} exists", id))?;
+        let function = proxy
+            .functions
+            .get(name)
+            .ok_or(format!("No method with the name '{}' exists", name))?;
+
+        ResultType::Ok((
+            proxy.env,
+            proxy.vm.clone(),
+            proxy.config.clone(),
+            proxy.env.get_reference_value::<JsFunction>(function)?,
+        ))
+    })?;
+
+    let mut args = vec![env.get_null()?.into_unknown()];
+    args.append(&mut create_call_args(&env, call.clone(), &vm, &config)?);
+
+    if let Err(err) = function.call(None, &args) {
+        let err = napi::JsError::from(err).into_unknown(env);
+        // The function may have already called the callback before throwing
+        call.set_result(Ok(Err(JsError::from_js(err)?))).ok();
+    }
+
+    // Java is blocking this thread, so a result passed to the callback
+    // later, for example once a promise resolves, would never arrive.
+    // Non-blocking calls don't block java, so their result may arrive later.
+    if must_return && !call.is_non_blocking() {
+        call.abandon(format!(
+            "The proxy method '{}' was called synchronously, but did not return a value synchronously",
+            name
+        ));
+    }
+
+    Ok(())
+}
+
+/// An event received by a node thread waiting for a java call to return.
+enum WaitEvent {
+    /// A proxy was called by java.
+    Call(ForwardedCall),
+    /// The java call returned.
+    Done,
+}
+
+/// Notifies the waiting node thread once dropped, so it
+/// stops waiting even if the java call panicked.
+struct NotifyDone(Sender<WaitEvent>);
+
+impl Drop for NotifyDone {
+    fn drop(&mut self) {
+        self.0.send(WaitEvent::Done).ok();
+    }
+}
+
+lazy_static! {
+    /// The node threads waiting for a java call to return, by wait id. Proxy
+    /// calls made while a node thread is waiting are passed to the thread which
+    /// started waiting last, as the java call it is waiting for may be blocked
+    /// until the proxy call returns.
+    static ref WAITING_THREADS: Mutex<Vec<(usize, Sender<WaitEvent>)>> = Mutex::new(Vec::new());
+}
+
+static NEXT_WAIT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Pass a proxy call to the node thread waiting for a java call to return, if any.
+/// Returns the call if no node thread is waiting.
+pub fn forward_call(
+    id: usize,
+    name: &str,
+    call: Arc<InterfaceCall>,
+) -> Result<(), Arc<InterfaceCall>> {
+    let waiting = WAITING_THREADS.lock().unwrap();
+    let Some((_, sender)) = waiting.last() else {
+        return Err(call);
+    };
+
+    sender
+        .send(WaitEvent::Call(ForwardedCall {
+            id,
+            name: name.to_string(),
+            call: call.clone(),
+            must_return: RUNS_AWAITED_CALL.get(),
+        }))
+        .map_err(|_| call)
+}
+
+/// Call a proxy forwarded to this thread. Proxies created on another
+/// node thread are called using their threadsafe function instead.
+/// Calls made by java threads other than the one running the awaited
+/// call may pass their result once the event loop runs again, e.g.
+/// if the function returns a promise, as these don't block this thread.
+fn call_forwarded(forwarded: ForwardedCall) {
+    let ForwardedCall {
+        id,
+        name,
+        call,
+        must_return,
+    } = forwarded;
+    let res = if can_call_directly(id) {
+        call_directly(id, &name, call.clone(), must_return)
+    } else {
+        call_threadsafe(id, &name, call.clone())
+    };
+
+    if let Err(err) = res {
+        call.set_result(Err(err.to_string())).ok();
+    }
+}
+
+/// Call `func` on a new thread and wait for it to return. Proxies called by
+/// java while waiting are passed to this thread and called directly, so this
+/// thread sleeps until either a proxy is called or `func` returns and never
+/// needs to run the event loop, which can't be done safely from a native call.
+pub fn call_handling_proxies<T: Send + 'static>(
+    func: impl FnOnce() -> T + Send + 'static,
+) -> napi::Result<T> {
+    let (sender, receiver) = channel::<WaitEvent>();
+    let wait_id = NEXT_WAIT_ID.fetch_add(1, Ordering::Relaxed);
+    WAITING_THREADS
+        .lock()
+        .unwrap()
+        .push((wait_id, sender.clone()));
+
+    let handle = thread::spawn(move || {
+        let _done = NotifyDone(sender);
+        RUNS_AWAITED_CALL.set(true);
+        func()
+    });
+
+    for event in receiver.iter() {
+        match event {
+            WaitEvent::Call(forwarded) => call_forwarded(forwarded),
+            WaitEvent::Done => break,
+        }
+    }
+
+    WAITING_THREADS
+        .lock()
+        .unwrap()
+        .retain(|(id, _)| *id != wait_id);
+
+    // Proxies may have been called after the java call returned,
+    // but before this thread stopped receiving proxy calls
+    for event in receiver.try_iter() {
+        if let WaitEvent::Call(forwarded) = event {
+            call_forwarded(forwarded);
+        }
+    }
+
+    handle
+        .join()
+        .map_err(|_| NapiError::from("Failed to join thread").into())
+}
@@ -1,11 +1,23 @@
 use crate::node::interface_proxy::interface_call::InterfaceCall;
 use crate::node::interface_proxy::js_error::JsError;
+use crate::node::interface_proxy::proxy_info::ProxyInfo;
 use java_rs::objects::object::GlobalJavaObject;
-use napi::threadsafe_function::ThreadsafeFunction;
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
-pub type MethodMap = HashMap<String, ThreadsafeFunction<InterfaceCall>>;
+pub type MethodMap = HashMap<String, ThreadsafeFunction<Arc<InterfaceCall>>>;
 pub type MethodsType = Arc<Mutex<MethodMap>>;
-pub type ProxiesType = HashMap<usize, MethodsType>;
+pub type ProxiesType = HashMap<usize, ProxyEntry>;
+/// Receives the method name and the error message of
+/// failed calls to non-blocking `void` methods of a proxy.
+pub type ErrorHandler = ThreadsafeFunction<(String, String), ErrorStrategy::Fatal>;
 pub type JsCallResult = Result<Result<Option<GlobalJavaObject>, JsError>, String>;
+
+/// A proxy registered in the proxy maps.
+#[derive(Clone)]
+pub struct ProxyEntry {
+    pub methods: MethodsType,
+    pub info: Arc<ProxyInfo>,
+    pub on_error: Option<ErrorHandler>,
+}
@@ -3,11 +3,15 @@ use crate::node::class_cache::ClassCache;
 use crate::node::config::ClassConfiguration;
 use crate::node::helpers::napi_error::{MapToNapiError, StrIntoNapiError};
 use crate::node::init::check_init_result;
+use crate::node::interface_proxy::function_proxy::wrap_function;
 use crate::node::interface_proxy::interface_proxy_options::InterfaceProxyOptions;
 use crate::node::interface_proxy::java_interface_proxy::JavaInterfaceProxy;
 use crate::node::java_class_instance::{JavaClassInstance, CLASS_PROXY_PROPERTY, OBJECT_PROPERTY};
 use crate::node::java_class_proxy::JavaClassProxy;
+use crate::node::java_future::{future_to_promise, is_future};
 use crate::node::java_options::JavaOptions;
+use crate::node::java_out_argument::JavaOutArgument;
+use crate::node::java_type_hint::JavaTypeHint;
 use crate::node::stdout_redirect::StdoutRedirect;
 use crate::node::util::helpers::{
     call_async_method_with_resolver, list_files, parse_array_or_string, parse_classpath_args,
@@ -218,21 +222,59 @@ impl Java {
         .map_napi_err(Some(env))
     }
 
+    /// Create a proxy implementing one or more java interfaces.
+    /// Methods may be named after their declaring interface and
+    /// their name, e.g. `java.lang.AutoCloseable.close`, to only
+    /// implement the method of that interface.
+    ///
+    /// Methods are called with an error, a callback receiving the
+    /// result and the converted arguments. If `returnValues` is set,
+    /// methods are only called with the converted arguments and may
+    /// either return the result or a promise resolving to it instead.
     #[napi]
     pub fn create_interface_proxy(
         &self,
         env: Env,
-        classname: String,
+        #[napi(ts_arg_type = "string | string[]")] classname: JsUnknown,
         #[napi(
-            ts_arg_type = "Record<string, (err: null | Error, callback: (err: Error | null, data?: any | null) => void, ...args: any[]) => void>"
+            ts_arg_type = "Record<string, (err: null | Error, callback: (err: Error | null, data?: any | null) => void, ...args: any[]) => void> | Record<string, (...args: any[]) => unknown>"
         )]
         methods: HashMap<String, JsFunction>,
         options: Option<InterfaceProxyOptions>,
+        return_values: Option<bool>,
     ) -> napi::Result<JavaInterfaceProxy> {
+        let interfaces = if classname.is_array()? {
+            let classname = classname.coerce_to_object()?;
+            (0..classname.get_array_length()?)
+                .map(|i| {
+                    classname
+                        .get_element::<JsUnknown>(i)?
+                        .coerce_to_string()?
+                        .into_utf16()?
+                        .as_str()
+                })
+                .collect::<napi::Result<Vec<_>>>()?
+        } else if classname.get_type()? == ValueType::String {
+            vec![classname.coerce_to_string()?.into_utf16()?.as_str()?]
+        } else {
+            return Err(
+                "'classname' must be either a string or an array of strings".into_napi_err()
+            );
+        };
+
+        let methods = if return_values.unwrap_or(false) {
+            methods
+                .into_iter()
+                .map(|(name, method)| Ok((name, wrap_function(&env, method)?)))
+                .collect::<napi::Result<HashMap<_, _>>>()?
+        } else {
+            methods
+        };
+
         JavaInterfaceProxy::new(
             self.root_vm.clone(),
             env,
-            classname,
+            interfaces,
             methods,
             options.unwrap_or_default(),
         )
@@ -251,6 +293,120 @@ impl Java {
         Self::_is_instance_of(env, &node_env, this_obj, other)
     }
 
+    /// Create a value with an explicit java type.
+    /// @param className the java type of the value, e.g. `short` or `java.lang.String`
+    /// @param value the value to wrap, only `null` is allowed for non-primitive types
+    /// @since 2.9.0
+    #[napi]
+    pub fn create_type_hint(
+        &self,
+        class_name: String,
+        #[napi(ts_arg_type = "number | bigint | string | boolean | null | undefined")]
+        value: JsUnknown,
+    ) -> napi::Result<JavaTypeHint> {
+        JavaTypeHint::new(self.root_vm.clone(), class_name, value)
+    }
+
+    /// Wrap an array to copy its contents back into
+    /// the array after it has been passed to a java method.
+    /// @param value the array or typed array to wrap
+    /// @since 2.9.0
+    #[napi]
+    pub fn create_out_argument(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "unknown[] | ArrayBufferView")] value: JsUnknown,
+    ) -> napi::Result<JavaOutArgument> {
+        JavaOutArgument::new(&env, value)
+    }
+
+    /// Get a specific overload of a method by its parameter types.
+    /// If `obj` is a class constructor, the static method will be returned,
+    /// otherwise the method will be bound to the instance `obj`.
+    ///
+    /// @param obj the java object or class to get the method from
+    /// @param name the name of the method
+    /// @param parameterTypes the java types of the method parameters
+    /// @param isAsync whether the returned function should return a promise
+    /// @since 2.9.0
+    #[napi(ts_return_type = "(...args: any[]) => any")]
+    pub fn get_method(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "object")] obj: JsUnknown,
+        name: String,
+        parameter_types: Vec<String>,
+        is_async: Option<bool>,
+    ) -> napi::Result<JsFunction> {
+        let is_static = obj.get_type()? == ValueType::Function;
+        let obj = obj.coerce_to_object()?;
+
+        let err_fn = |_| "'obj' is not a java object or class".into_napi_err();
+        let proxy_obj: JsObject = obj
+            .get_named_property(CLASS_PROXY_PROPERTY)
+            .map_err(err_fn)?;
+        let proxy = env
+            .unwrap::<Arc<JavaClassProxy>>(&proxy_obj)
+            .map_err(err_fn)?
+            .clone();
+
+        let instance = if is_static {
+            None
+        } else {
+            let instance_obj: JsObject = obj.get_named_property(OBJECT_PROPERTY)?;
+            Some(env.unwrap::<GlobalJavaObject>(&instance_obj)?.clone())
+        };
+
+        let method = proxy
+            .find_method_by_signature(&name, &parameter_types, is_static)
+            .map_napi_err(Some(env))?
+            .clone();
+
+        JavaClassInstance::create_bound_method(
+            &env,
+            proxy,
+            method,
+            instance,
+            is_async.unwrap_or(false),
+        )
+    }
+
+    /// Convert a java future to a promise.
+    /// The future is cancelled once `signal` is aborted.
+    ///
+    /// @param future the `java.util.concurrent.CompletionStage`
+    ///               or `java.util.concurrent.Future` to convert
+    /// @param signal an optional signal used to cancel the future
+    /// @since 2.9.0
+    #[napi(ts_return_type = "Promise<any>")]
+    pub fn future_to_promise(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "object")] future: JsObject,
+        #[napi(ts_arg_type = "AbortSignal | undefined | null")] signal: Option<JsObject>,
+    ) -> napi::Result<JsObject> {
+        let err_fn = |_| "'future' is not a java object".into_napi_err();
+        let proxy_obj: JsObject = future
+            .get_named_property(CLASS_PROXY_PROPERTY)
+            .map_err(err_fn)?;
+        let proxy = env
+            .unwrap::<Arc<JavaClassProxy>>(&proxy_obj)
+            .map_err(err_fn)?;
+        let instance_obj: JsObject = future.get_named_property(OBJECT_PROPERTY).map_err(err_fn)?;
+        let instance = env
+            .unwrap::<GlobalJavaObject>(&instance_obj)
+            .map_err(err_fn)?
+            .clone();
+
+        if !is_future(&instance).map_napi_err(Some(env))? {
+            return Err(
+                "'future' is not a java.util.concurrent.Future or CompletionStage".into_napi_err(),
+            );
+        }
+
+        future_to_promise(&env, &self.root_vm, instance, &proxy.config, signal)
+    }
+
     #[napi(getter, ts_return_type = "object")]
     pub fn get_class_loader(&self, env: Env) -> napi::Result<JsUnknown> {
         let proxy = MutAppState::<ClassCache>::get_or_insert_default()
@@ -1,12 +1,16 @@
 #![allow(clippy::zero_repeat_side_effects)]
+use crate::java::class_method::ClassMethod;
 use crate::node::extensions::java_call_result_ext::ToNapiValue;
 use crate::node::extensions::java_type_ext::NapiToJava;
-use crate::node::helpers::arg_convert::{call_context_to_java_args, call_results_to_args};
-use crate::node::helpers::napi_error::{MapToNapiError, NapiError};
-use crate::node::helpers::napi_ext::{load_napi_library, uv_run, uv_run_mode};
+use crate::node::helpers::arg_convert::{
+    apply_array_write_backs, call_context_to_java_args, call_results_to_args, get_array_write_backs,
+};
+use crate::node::helpers::napi_error::MapToNapiError;
 use crate::node::interface_proxy::proxies::interface_proxy_exists;
+use crate::node::interface_proxy::reentrant_calls::call_handling_proxies;
 use crate::node::java::Java;
 use crate::node::java_class_proxy::JavaClassProxy;
+use crate::node::java_iterator::add_iterators;
 use crate::node::util::helpers::{call_async_method, call_async_method_with_resolver};
 use crate::node::util::traits::UnwrapOrEmpty;
 use java_rs::java_call_result::JavaCallResult;
@@ -17,9 +21,8 @@ use napi::{
     CallContext, Callback, Env, JsBoolean, JsFunction, JsObject, JsUnknown, Property,
     PropertyAttributes, Status,
 };
+use std::collections::HashSet;
 use std::sync::Arc;
-use std::thread;
-use std::time::Duration;
 
 pub const CLASS_PROXY_PROPERTY: &str = "class.proxy";
 pub const OBJECT_PROPERTY: &str = "class.object";
@@ -56,21 +59,33 @@ impl JavaClassInstance {
 
             constructor.set_named_property(
                 name_sync.clone().as_str(),
-                env.create_function_from_closure(
-                    name_sync.clone().as_str(),
-                    move |ctx: CallContext| -> napi::Result<JsUnknown> {
-                        Self::call_static_method(&ctx, &name_cpy)
-                    },
+                Self::add_overloads(
+                    env.create_function_from_closure(
+                        name_sync.clone().as_str(),
+                        move |ctx: CallContext| -> napi::Result<JsUnknown> {
+                            Self::call_static_method(&ctx, &name_cpy)
+                        },
+                    )?,
+                    &constructor,
+                    method.0,
+                    method.1,
+                    false,
                 )?,
             )?;
 
             constructor.set_named_property(
                 name_async.clone().as_str(),
-                env.create_function_from_closure(
-                    name_async.as_str(),
-                    move |ctx: CallContext| -> napi::Result<JsObject> {
-                        Self::call_static_method_async(&ctx, &name)
-                    },
+                Self::add_overloads(
+                    env.create_function_from_closure(
+                        name_async.as_str(),
+                        move |ctx: CallContext| -> napi::Result<JsObject> {
+                            Self::call_static_method_async(&ctx, &name)
+                        },
+                    )?,
+                    &constructor,
+                    method.0,
+                    method.1,
+                    true,
                 )?,
             )?;
         }
@@ -95,7 +110,8 @@ impl JavaClassInstance {
 
                             let res = field.get_static().map_napi_err(Some(env))?;
                             let j_env = proxy.vm.attach_thread().map_napi_err(Some(env))?;
-                            res.to_napi_value(&j_env, &env).map_napi_err(Some(env))
+                            res.to_napi_value(&j_env, &env, &proxy.config)
+                                .map_napi_err(Some(env))
                         });
 
                     if !field.is_final() {
@@ -112,7 +128,7 @@ impl JavaClassInstance {
                                 let field_type = field.get_type();
                                 let j_env = proxy.vm.attach_thread().map_napi_err(Some(env))?;
                                 let val = field_type
-                                    .convert_to_java_value(&j_env, &env, value)
+                                    .convert_to_java_value(&j_env, &env, value, &proxy.config)
                                     .map_napi_err(Some(env))?;
 
                                 field.set_static(val).map_napi_err(Some(env))?;
@@ -150,13 +166,15 @@ impl JavaClassInstance {
         instance: GlobalJavaObject,
     ) -> napi::Result<()> {
         let mut instance_obj = env.create_object()?;
-        env.wrap(&mut instance_obj, instance)?;
+        env.wrap(&mut instance_obj, instance.clone())?;
         this.set_named_property(OBJECT_PROPERTY, instance_obj)?;
 
         if proxy.config.custom_inspect {
             Self::add_custom_inspect(env, this)?;
         }
 
+        add_iterators(env, this, proxy, &instance)?;
+
         for method in &proxy.methods {
             if method.0 == "toString" {
                 this.set_named_property(
@@ -190,21 +208,33 @@ impl JavaClassInstance {
 
             this.set_named_property(
                 name_sync.clone().as_str(),
-                env.create_function_from_closure(
-                    name_sync.clone().as_str(),
-                    move |ctx: CallContext| -> napi::Result<JsUnknown> {
-                        Self::call_method(&ctx, &name_cpy)
-                    },
+                Self::add_overloads(
+                    env.create_function_from_closure(
+                        name_sync.clone().as_str(),
+                        move |ctx: CallContext| -> napi::Result<JsUnknown> {
+                            Self::call_method(&ctx, &name_cpy)
+                        },
+                    )?,
+                    this,
+                    method.0,
+                    method.1,
+                    false,
                 )?,
             )?;
 
             this.set_named_property(
                 name_async.clone().as_str(),
-                env.create_function_from_closure(
-                    name_async.as_str(),
-                    move |ctx: CallContext| -> napi::Result<JsObject> {
-                        Self::call_method_async(&ctx, &name)
-                    },
+                Self::add_overloads(
+                    env.create_function_from_closure(
+                        name_async.as_str(),
+                        move |ctx: CallContext| -> napi::Result<JsObject> {
+                            Self::call_method_async(&ctx, &name)
+                        },
+                    )?,
+                    this,
+                    method.0,
+                    method.1,
+                    true,
                 )?,
             )?;
         }
@@ -232,7 +262,8 @@ impl JavaClassInstance {
 
                             let res = field.get(obj).map_napi_err(Some(env))?;
                             let j_env = proxy.vm.attach_thread().map_napi_err(Some(env))?;
-                            res.to_napi_value(&j_env, &env).map_napi_err(Some(env))
+                            res.to_napi_value(&j_env, &env, &proxy.config)
+                                .map_napi_err(Some(env))
                         });
 
                     if !field.is_final() {
@@ -251,7 +282,7 @@ impl JavaClassInstance {
                             let field_type = field.get_type();
                             let j_env = proxy.vm.attach_thread().map_napi_err(Some(env))?;
                             let val = field_type
-                                .convert_to_java_value(&j_env, &env, value)
+                                .convert_to_java_value(&j_env, &env, value, &proxy.config)
                                 .map_napi_err(Some(env))?;
 
                             field.set(obj, val).map_napi_err(Some(env))
@@ -308,19 +339,38 @@ impl JavaClassInstance {
             .find_matching_method(ctx, name, true, false)
             .or_else(|_| proxy.find_matching_method(ctx, name, true, true))
             .map_napi_err(Some(*ctx.env))?;
+
+        Self::invoke_static_method(ctx, proxy, method)
+    }
+
+    fn invoke_static_method(
+        ctx: &CallContext,
+        proxy: &JavaClassProxy,
+        method: &ClassMethod,
+    ) -> napi::Result<JsUnknown> {
         let env = proxy.vm.attach_thread().map_napi_err(Some(*ctx.env))?;
-        let args = call_context_to_java_args(ctx, method.parameter_types(), &env)?;
+        let (args, function_proxies) =
+            call_context_to_java_args(ctx, method.parameter_types(), &env, &proxy.config)?;
+        let write_backs =
+            get_array_write_backs(ctx, method.parameter_types(), &args, &proxy.config)?;
         let args_ref = call_results_to_args(&args);
 
         #[cfg(feature = "log")]
-        log::debug!("Calling static method: {}.{}()", proxy.class_name, name);
-
-        let res = method
-            .call_static(args_ref.as_slice())
+        log::debug!(
+            "Calling static method: {}.{}()",
+            proxy.class_name,
+            method.name()
+        );
+
+        let res = method.call_static(args_ref.as_slice());
+        drop(function_proxies);
+        apply_array_write_backs(ctx.env, &env, write_backs, &proxy.config)?;
+        let res = res
+            .map_napi_err(Some(*ctx.env))?
+            .to_napi_value(&env, ctx.env, &proxy.config)
             .map_napi_err(Some(*ctx.env))?;
 
-        res.to_napi_value(&env, ctx.env)
-            .map_napi_err(Some(*ctx.env))
+        Ok(res)
     }
 
     fn call_static_method_async(ctx: &CallContext, name: &String) -> napi::Result<JsObject> {
@@ -330,13 +380,29 @@ impl JavaClassInstance {
             .or_else(|_| proxy.find_matching_method(ctx, name, true, true))
             .map_napi_err(Some(*ctx.env))?
             .clone();
+
+        Self::invoke_static_method_async(ctx, proxy, method)
+    }
+
+    fn invoke_static_method_async(
+        ctx: &CallContext,
+        proxy: Arc<JavaClassProxy>,
+        method: ClassMethod,
+    ) -> napi::Result<JsObject> {
         let env = proxy.vm.attach_thread().map_napi_err(Some(*ctx.env))?;
-        let args = call_context_to_java_args(ctx, method.parameter_types(), &env)?;
+        let (args, function_proxies) =
+            call_context_to_java_args(ctx, method.parameter_types(), &env, &proxy.config)?;
+        let write_backs =
+            get_array_write_backs(ctx, method.parameter_types(), &args, &proxy.config)?;
 
         #[cfg(feature = "log")]
-        log::debug!("Calling static method: {}.{}()", proxy.class_name, name);
+        log::debug!(
+            "Calling static method: {}.{}()",
+            proxy.class_name,
+            method.name()
+        );
 
-        call_async_method(*ctx.env, proxy, move || {
+        call_async_method(*ctx.env, proxy, write_backs, function_proxies, move || {
             let args_ref = call_results_to_args(&args);
             method.call_static(args_ref.as_slice())
         })
@@ -350,56 +416,55 @@ impl JavaClassInstance {
             .map_napi_err(Some(*ctx.env))?;
         let obj = Self::get_object(ctx)?;
 
+        Self::invoke_method(ctx, proxy, method, obj)
+    }
+
+    fn invoke_method(
+        ctx: &CallContext,
+        proxy: &JavaClassProxy,
+        method: &ClassMethod,
+        obj: &GlobalJavaObject,
+    ) -> napi::Result<JsUnknown> {
         let env = proxy.vm.attach_thread().map_napi_err(Some(*ctx.env))?;
-        let args = call_context_to_java_args(ctx, method.parameter_types(), &env)?;
+        let (args, function_proxies) =
+            call_context_to_java_args(ctx, method.parameter_types(), &env, &proxy.config)?;
+        let write_backs =
+            get_array_write_backs(ctx, method.parameter_types(), &args, &proxy.config)?;
 
         #[cfg(feature = "log")]
-        log::debug!("Calling method: {}.{}()", proxy.class_name, name);
+        log::debug!("Calling method: {}.{}()", proxy.class_name, method.name());
 
         let result = if proxy.config.run_event_loop_when_interface_proxy_is_active
             && interface_proxy_exists()
         {
-            // If the call context contains an interface proxy, we need to call the method
-            // on a different thread as calling it on the same thread may cause a deadlock.
-            // Additionally, we need to run the event loop to allow the javascript thread to
-            // run the callback.
+            // Proxies called by java on this thread are called directly, but proxies
+            // called by other java threads must be called by this thread. The method
+            // is called on a different thread while this thread waits for either
+            // the method to return or a proxy to be called.
             let cloned_obj = obj.clone();
             let cloned_method = method.clone();
 
-            // Load the uv_run function from the uv library
-            load_napi_library();
-
-            let handle = thread::spawn(move || -> napi::Result<JavaCallResult> {
+            call_handling_proxies(move || {
                 let args_ref = call_results_to_args(&args);
                 cloned_method
                     .call(&cloned_obj, args_ref.as_slice())
                     .map_napi_err(None)
-            });
-
-            while !handle.is_finished() {
-                unsafe {
-                    uv_run(ctx.env.get_uv_event_loop()?, uv_run_mode::UV_RUN_NOWAIT);
-                }
-
-                spin_sleep::sleep(Duration::from_micros(1));
-            }
-
-            handle
-                .join()
-                .map_err(|_| NapiError::from("Failed to join thread").into_napi())??
+            })?
         } else {
-            let env = proxy.vm.attach_thread().map_napi_err(Some(*ctx.env))?;
-            let args = call_context_to_java_args(ctx, method.parameter_types(), &env)?;
             let args_ref = call_results_to_args(&args);
 
             method
                 .call(obj, args_ref.as_slice())
-                .map_napi_err(Some(*ctx.env))?
+                .map_napi_err(Some(*ctx.env))
         };
 
-        result
-            .to_napi_value(&env, ctx.env)
-            .map_napi_err(Some(*ctx.env))
+        drop(function_proxies);
+        apply_array_write_backs(ctx.env, &env, write_backs, &proxy.config)?;
+        let res = result?
+            .to_napi_value(&env, ctx.env, &proxy.config)
+            .map_napi_err(Some(*ctx.env))?;
+
+        Ok(res)
     }
 
     fn call_method_async(ctx: &CallContext, name: &String) -> napi::Result<JsObject> {
@@ -410,18 +475,155 @@ impl JavaClassInstance {
             .map_napi_err(Some(*ctx.env))?
             .clone();
         let obj = Self::get_object(ctx)?.clone();
+
+        Self::invoke_method_async(ctx, proxy, method, obj)
+    }
+
+    fn invoke_method_async(
+        ctx: &CallContext,
+        proxy: Arc<JavaClassProxy>,
+        method: ClassMethod,
+        obj: GlobalJavaObject,
+    ) -> napi::Result<JsObject> {
         let env = proxy.vm.attach_thread().map_napi_err(Some(*ctx.env))?;
-        let args = call_context_to_java_args(ctx, method.parameter_types(), &env)?;
+        let (args, function_proxies) =
+            call_context_to_java_args(ctx, method.parameter_types(), &env, &proxy.config)?;
+        let write_backs =
+            get_array_write_backs(ctx, method.parameter_types(), &args, &proxy.config)?;
 
         #[cfg(feature = "log")]
-        log::debug!("Calling method: {}.{}()", proxy.class_name, name);
+        log::debug!("Calling method: {}.{}()", proxy.class_name, method.name());
 
-        call_async_method(*ctx.env, proxy, move || {
+        call_async_method(*ctx.env, proxy, write_backs, function_proxies, move || {
             let args_ref = call_results_to_args(&args);
             method.call(&obj, args_ref.as_slice())
         })
     }
 
+    /// Create a function calling `method` directly, without
+    /// trying to find a matching overload for the arguments.
+    /// If `instance` is set, the method will be called on that
+    /// instance, otherwise the method is called statically.
+    pub fn create_bound_method(
+        env: &Env,
+        proxy: Arc<JavaClassProxy>,
+        method: ClassMethod,
+        instance: Option<GlobalJavaObject>,
+        is_async: bool,
+    ) -> napi::Result<JsFunction> {
+        let name = method.name().clone();
+        match (instance, is_async) {
+            (Some(instance), false) => env.create_function_from_closure(
+                &name,
+                move |ctx: CallContext| -> napi::Result<JsUnknown> {
+                    Self::invoke_method(&ctx, &proxy, &method, &instance)
+                },
+            ),
+            (Some(instance), true) => env.create_function_from_closure(
+                &name,
+                move |ctx: CallContext| -> napi::Result<JsObject> {
+                    Self::invoke_method_async(&ctx, proxy.clone(), method.clone(), instance.clone())
+                },
+            ),
+            (None, false) => env.create_function_from_closure(
+                &name,
+                move |ctx: CallContext| -> napi::Result<JsUnknown> {
+                    Self::invoke_static_method(&ctx, &proxy, &method)
+                },
+            ),
+            (None, true) => env.create_function_from_closure(
+                &name,
+                move |ctx: CallContext| -> napi::Result<JsObject> {
+                    Self::invoke_static_method_async(&ctx, proxy.clone(), method.clone())
+                },
+            ),
+        }
+    }
+
+    /// Add all overloads of a method to the function `func`,
+    /// keyed by their parameter signature, e.g. `(int,java.lang.String)`.
+    /// This is only done if the method is actually overloaded. The bound
+    /// functions are only created once an overload is accessed, as creating
+    /// these for every method of every instance is rather expensive.
+    /// `owner` is either the class constructor or the instance `func`
+    /// belongs to.
+    fn add_overloads(
+        func: JsFunction,
+        owner: &JsObject,
+        name: &str,
+        methods: &[ClassMethod],
+        is_async: bool,
+    ) -> napi::Result<JsFunction> {
+        if methods.len() < 2 {
+            return Ok(func);
+        }
+
+        let mut obj = func.coerce_to_object()?;
+        let is_static = !owner.has_named_property(OBJECT_PROPERTY)?;
+        let proxy_obj: JsObject = owner.get_named_property(CLASS_PROXY_PROPERTY)?;
+        let mut properties = vec![Property::new(CLASS_PROXY_PROPERTY)?
+            .with_value(&proxy_obj)
+            .with_property_attributes(PropertyAttributes::Default)];
+
+        if !is_static {
+            let instance_obj: JsObject = owner.get_named_property(OBJECT_PROPERTY)?;
+            properties.push(
+                Property::new(OBJECT_PROPERTY)?
+                    .with_value(&instance_obj)
+                    .with_property_attributes(PropertyAttributes::Default),
+            );
+        }
+
+        // Bridge methods may share the signature of the method they bridge
+        let mut signatures = HashSet::new();
+        for (i, method) in methods.iter().enumerate() {
+            let signature = method.parameter_signature();
+            if !signatures.insert(signature.clone()) {
+                continue;
+            }
+
+            let name = name.to_string();
+            properties.push(
+                Property::new(signature.as_str())?
+                    .with_property_attributes(PropertyAttributes::Enumerable)
+                    .with_getter_closure(move |env, this| {
+                        Self::get_overload(env, this, &name, i, is_static, is_async)
+                    }),
+            );
+        }
+
+        obj.define_properties(properties.as_ref())?;
+
+        JsFunction::try_from(obj.into_unknown())
+    }
+
+    fn get_overload(
+        env: Env,
+        func: JsObject,
+        name: &String,
+        index: usize,
+        is_static: bool,
+        is_async: bool,
+    ) -> napi::Result<JsFunction> {
+        let proxy_obj: JsObject = func.get_named_property(CLASS_PROXY_PROPERTY)?;
+        let proxy = env.unwrap::<Arc<JavaClassProxy>>(&proxy_obj)?.clone();
+        let instance = if is_static {
+            None
+        } else {
+            let instance_obj: JsObject = func.get_named_property(OBJECT_PROPERTY)?;
+            Some(env.unwrap::<GlobalJavaObject>(&instance_obj)?.clone())
+        };
+
+        let methods = if is_static {
+            &proxy.static_methods
+        } else {
+            &proxy.methods
+        };
+        let method = methods[name][index].clone();
+
+        Self::create_bound_method(&env, proxy, method, instance, is_async)
+    }
+
     fn add_custom_inspect(env: &Env, this: &mut JsObject) -> napi::Result<()> {
         let custom = env
             .get_global()?
@@ -448,7 +650,7 @@ impl JavaClassInstance {
                     let env = proxy.vm.attach_thread().map_napi_err(Some(*ctx.env))?;
 
                     let res = method.call(obj, &[]).map_napi_err(Some(*ctx.env))?;
-                    res.to_napi_value(&env, ctx.env)
+                    res.to_napi_value(&env, ctx.env, &proxy.config)
                         .map_napi_err(Some(*ctx.env))
                 },
             )?,
@@ -474,15 +676,19 @@ fn constructor(ctx: CallContext) -> napi::Result<JsUnknown> {
         .map_napi_err(Some(*ctx.env))?;
     let env = proxy.vm.attach_thread().map_napi_err(Some(*ctx.env))?;
 
-    let args = call_context_to_java_args(&ctx, constructor.parameter_types(), &env)?;
+    let (args, function_proxies) =
+        call_context_to_java_args(&ctx, constructor.parameter_types(), &env, &proxy.config)?;
+    let write_backs =
+        get_array_write_backs(&ctx, constructor.parameter_types(), &args, &proxy.config)?;
     let args_ref = call_results_to_args(&args);
 
     #[cfg(feature = "log")]
     log::debug!("Creating new instance of class: {}", proxy.class_name);
 
-    let instance = constructor
-        .new_instance(args_ref.as_slice())
-        .map_napi_err(Some(*ctx.env))?;
+    let instance = constructor.new_instance(args_ref.as_slice());
+    drop(function_proxies);
+    apply_array_write_backs(ctx.env, &env, write_backs, &proxy.config)?;
+    let instance = instance.map_napi_err(Some(*ctx.env))?;
 
     this.set_named_property(CLASS_PROXY_PROPERTY, proxy_obj)?;
 
@@ -499,19 +705,32 @@ fn new_instance(ctx: CallContext) -> napi::Result<JsObject> {
         .map_napi_err(Some(*ctx.env))?
         .clone();
     let env = proxy.vm.attach_thread().map_napi_err(Some(*ctx.env))?;
-    let args = call_context_to_java_args(&ctx, constructor.parameter_types(), &env)?;
+    let (args, function_proxies) =
+        call_context_to_java_args(&ctx, constructor.parameter_types(), &env, &proxy.config)?;
+    let write_backs =
+        get_array_write_backs(&ctx, constructor.parameter_types(), &args, &proxy.config)?;
 
     #[cfg(feature = "log")]
     log::debug!("Creating new instance of class: {}", proxy.class_name);
 
     call_async_method_with_resolver(
         *ctx.env,
-        proxy.async_java_exception_objects(),
+        true,
         move || {
             let args_ref = call_results_to_args(&args);
-            constructor.new_instance(args_ref.as_slice())
+            Ok(constructor.new_instance(args_ref.as_slice()))
+        },
+        move |env, instance| {
+            let j_env = proxy.vm.attach_thread().map_napi_err(Some(*env))?;
+            apply_array_write_backs(env, &j_env, write_backs, &proxy.config)?;
+
+            let error_env = proxy.async_java_exception_objects().then_some(*env);
+            let instance = instance.map_napi_err(error_env)?;
+            let res = JavaClassInstance::from_existing(proxy, env, instance)?;
+            drop(function_proxies);
+
+            Ok(res)
         },
-        move |env, instance| JavaClassInstance::from_existing(proxy, env, instance),
     )
 }
 
@@ -532,7 +751,7 @@ fn get_class_field(ctx: CallContext) -> napi::Result<JsObject> {
         signature: JavaType::new("java.lang.Class".to_string(), false),
     };
 
-    res.to_napi_value(&j_env, ctx.env)
+    res.to_napi_value(&j_env, ctx.env, &proxy.config)
         .map_napi_err(Some(*ctx.env))?
         .coerce_to_object()
 }
@@ -3,6 +3,8 @@ use crate::java::class_field::ClassField;
 use crate::java::class_method::ClassMethod;
 use crate::node::config::Config;
 use crate::node::extensions::class_ext::ArgumentMatch;
+use crate::node::java_iterator::IterableKind;
+use java_rs::java_type::JavaType;
 use java_rs::java_vm::JavaVM;
 use java_rs::objects::class::GlobalJavaClass;
 use java_rs::util::helpers::ResultType;
@@ -19,12 +21,14 @@ pub struct JavaClassProxy {
     pub constructors: Vec<ClassConstructor>,
     pub class_name: String,
     pub config: Config,
+    pub iterable_kind: Option<IterableKind>,
 }
 
 impl JavaClassProxy {
     pub fn new(vm: JavaVM, class_name: String, config: Option<Config>) -> ResultType<Self> {
         let env = vm.attach_thread()?;
         let class = env.find_global_class_by_java_name(class_name.clone())?;
+        let iterable_kind = IterableKind::of_class(&env, &class)?;
 
         Ok(Self {
             vm: vm.clone(),
@@ -34,6 +38,7 @@ impl JavaClassProxy {
             fields: ClassField::get_class_fields(vm.clone(), class_name.clone(), false)?,
             static_fields: ClassField::get_class_fields(vm.clone(), class_name.clone(), true)?,
             constructors: ClassConstructor::get_constructors(vm, class_name.clone())?,
+            iterable_kind,
             class_name,
             config: config.unwrap_or_else(|| Config::get().clone()),
         })
@@ -82,6 +87,53 @@ impl JavaClassProxy {
             )
     }
 
+    /// Find a method by its name and exact parameter types.
+    /// The parameter types may be given as java (`java.lang.String`)
+    /// or jni (`Ljava/lang/String;`) signatures.
+    pub fn find_method_by_signature(
+        &self,
+        name: &String,
+        parameter_types: &[String],
+        only_static: bool,
+    ) -> ResultType<&ClassMethod> {
+        let methods = if only_static {
+            &self.static_methods
+        } else {
+            &self.methods
+        }
+        .get(name)
+        .ok_or(format!("No method found with name '{}'", name))?;
+
+        let parameter_types = parameter_types
+            .iter()
+            .map(|t| JavaType::new(t.trim().to_string(), true))
+            .collect::<Vec<_>>();
+
+        methods
+            .iter()
+            .find(|m| m.parameter_types().as_slice() == parameter_types.as_slice())
+            .ok_or(
+                format!(
+                    "No method found with name '{}' and signature ({}). Options were:\n{}",
+                    name,
+                    parameter_types
+                        .iter()
+                        .map(|t| t.to_string())
+                        .collect::<Vec<String>>()
+                        .join(","),
+                    methods
+                        .iter()
+                        .map(|m| {
+                            let static_prefix = if only_static { "static " } else { "" };
+                            format!("\tpublic {}{}", static_prefix, m)
+                        })
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                )
+                .into(),
+            )
+    }
+
     pub fn find_matching_constructor(
         &self,
         ctx: &CallContext,
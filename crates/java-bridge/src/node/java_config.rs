@@ -1,4 +1,4 @@
-use crate::node::config::Config;
+use crate::node::config::{BigDecimalMode, Config, LongMode};
 use crate::node::util::traits::UnwrapOrEmpty;
 
 /// Configuration options for the java bridge.
@@ -29,19 +29,19 @@ impl JavaConfig {
 
     /// **Experimental Feature**
     ///
-    /// Set whether to run the event loop when an interface proxy is active.
-    /// This is disabled by default. Enabling this will cause the bridge
-    /// to run the event loop when an interface proxy either as direct
-    /// proxy or as daemon proxy is active. This is only required if the
-    /// proxied method calls back into the javascript process in the same thread.
-    /// If the proxy is used either in an async method or in a different thread,
-    /// this is not required.
-    ///
-    /// **Note:** Enabling this may cause the application to crash. Use with caution.
+    /// Set whether to call interface proxies while waiting for synchronous calls.
+    /// This is disabled by default. Enabling this will cause the bridge to
+    /// call synchronous methods on a different thread while an interface proxy
+    /// either as direct proxy or as daemon proxy is active. The node thread
+    /// sleeps until either the method returns or java calls a proxy on another
+    /// thread, in which case the proxy method is called on the node thread.
+    /// This is only required if the proxy is called by a different java
+    /// thread while a synchronous method is running.
+    /// If the proxy is used in an async method, this is not required.
     ///
     /// @since 2.2.3
     /// @experimental
-    /// @param value whether to run the event loop when an interface proxy is active
+    /// @param value whether to call interface proxies while waiting for synchronous calls
     #[napi(setter)]
     pub fn set_run_event_loop_when_interface_proxy_is_active(&self, value: bool) {
         Config::get().run_event_loop_when_interface_proxy_is_active = value;
@@ -49,7 +49,7 @@ impl JavaConfig {
 
     /// **Experimental Feature**
     ///
-    /// Get whether to run the event loop when an interface proxy is active.
+    /// Get whether to call interface proxies while waiting for synchronous calls.
     /// @since 2.2.3
     /// @experimental
     #[napi(getter)]
@@ -187,6 +187,416 @@ impl JavaConfig {
         Config::get().async_java_exception_objects = value;
     }
 
+    /// Get how java `long` values are converted to javascript values.
+    ///
+    /// @since 2.9.0
+    #[napi(getter, ts_return_type = "'bigint' | 'number' | 'auto' | undefined")]
+    pub fn get_long_mode(&self) -> Option<LongMode> {
+        Config::get().long_mode
+    }
+
+    /// Set how java `long` values are converted to javascript values.
+    /// `'bigint'` always returns `BigInt`s, `'number'` always returns
+    /// `number`s and `'auto'` returns a `number` if the value is smaller
+    /// than or equal to `Number.MAX_SAFE_INTEGER` and a `BigInt` otherwise.
+    /// This applies to `long`, `java.lang.Long` and `long[]` values.
+    ///
+    /// If not set, `long` and `java.lang.Long` values are converted
+    /// to `BigInt`s and the values of `long[]` are converted to `number`s.
+    ///
+    /// ## Example
+    /// ```ts
+    /// import { config, clearClassProxies, importClass } from 'java-bridge';
+    ///
+    /// config.longMode = 'auto';
+    /// clearClassProxies();
+    ///
+    /// const Long = importClass('java.lang.Long');
+    /// Long.valueOfSync(5); // 5
+    /// Long.MAX_VALUE; // 9223372036854775807n
+    /// ```
+    ///
+    /// @since 2.9.0
+    /// @param value the long mode to use
+    #[napi(
+        setter,
+        ts_args_type = "value: 'bigint' | 'number' | 'auto' | undefined | null"
+    )]
+    pub fn set_long_mode(&self, value: Option<LongMode>) {
+        Config::get().long_mode = value;
+    }
+
+    /// Get whether lossy number conversions should be rejected.
+    ///
+    /// @since 2.9.0
+    #[napi(getter)]
+    pub fn get_strict_numbers(&self) -> Option<bool> {
+        Config::get().strict_numbers
+    }
+
+    /// If true, javascript numbers which cannot be converted to
+    /// the required java type without losing information will
+    /// throw an error instead of being silently truncated or wrapped.
+    /// This applies to non-integral values passed as `byte`, `short`,
+    /// `int` or `long`, values out of range for `byte`, `short` or
+    /// `int` and numbers larger than `Number.MAX_SAFE_INTEGER` passed
+    /// as `long`. Use a `BigInt` to pass such values as `long`.
+    /// Integers outside the `int` range passed as `java.lang.Object`
+    /// are converted to `java.lang.Long` instead.
+    ///
+    /// ## Example
+    /// ```ts
+    /// import { config, clearClassProxies, importClass } from 'java-bridge';
+    ///
+    /// config.strictNumbers = true;
+    /// clearClassProxies();
+    ///
+    /// const Integer = importClass('java.lang.Integer');
+    /// Integer.valueOfSync(3.7); // throws an error
+    /// ```
+    ///
+    /// @since 2.9.0
+    /// @param value whether to reject lossy number conversions
+    #[napi(setter, ts_args_type = "value: boolean | undefined | null")]
+    pub fn set_strict_numbers(&self, value: Option<bool>) {
+        Config::get().strict_numbers = value;
+    }
+
+    /// Get whether java collections are converted to javascript values.
+    ///
+    /// @since 2.9.0
+    #[napi(getter)]
+    pub fn get_convert_collections(&self) -> Option<bool> {
+        Config::get().convert_collections
+    }
+
+    /// If true, `java.util.List`, `java.util.Set` and `java.util.Map`
+    /// values returned from java will be converted to javascript
+    /// arrays, `Set`s and `Map`s in a single pass, converting their
+    /// elements recursively. Otherwise, these values will be returned
+    /// as java objects. Javascript arrays, `Set`s, `Map`s and plain
+    /// objects are always accepted as collection and map parameters.
+    ///
+    /// ## Example
+    /// ```ts
+    /// import { config, clearClassProxies, importClass } from 'java-bridge';
+    ///
+    /// config.convertCollections = true;
+    /// clearClassProxies();
+    ///
+    /// const List = importClass('java.util.List');
+    /// List.ofSync(1, 2, 3); // [1, 2, 3]
+    /// ```
+    ///
+    /// @since 2.9.0
+    /// @param value whether to convert java collections
+    #[napi(setter, ts_args_type = "value: boolean | undefined | null")]
+    pub fn set_convert_collections(&self, value: Option<bool>) {
+        Config::get().convert_collections = value;
+    }
+
+    /// Get whether java futures are converted to javascript promises.
+    ///
+    /// @since 2.9.0
+    #[napi(getter)]
+    pub fn get_convert_futures(&self) -> Option<bool> {
+        Config::get().convert_futures
+    }
+
+    /// If true, `java.util.concurrent.CompletionStage` and
+    /// `java.util.concurrent.Future` values returned from java
+    /// will be converted to javascript promises. Completion stages
+    /// resolve the promise once they complete, other futures are
+    /// waited for on a background thread. If the future completes
+    /// exceptionally, the promise is rejected with an error whose
+    /// `cause` is the java exception. Use {@link futureToPromise}
+    /// in order to cancel a future using an `AbortSignal`.
+    ///
+    /// ## Example
+    /// ```ts
+    /// import { config, clearClassProxies, importClass } from 'java-bridge';
+    ///
+    /// config.convertFutures = true;
+    /// clearClassProxies();
+    ///
+    /// const CompletableFuture = importClass(
+    ///     'java.util.concurrent.CompletableFuture'
+    /// );
+    /// await CompletableFuture.completedFutureSync('test'); // 'test'
+    /// ```
+    ///
+    /// @since 2.9.0
+    /// @param value whether to convert java futures
+    #[napi(setter, ts_args_type = "value: boolean | undefined | null")]
+    pub fn set_convert_futures(&self, value: Option<bool>) {
+        Config::get().convert_futures = value;
+    }
+
+    /// Get whether java optionals are unwrapped.
+    ///
+    /// @since 2.9.0
+    #[napi(getter)]
+    pub fn get_unwrap_optionals(&self) -> Option<bool> {
+        Config::get().unwrap_optionals
+    }
+
+    /// If true, `java.util.Optional`, `java.util.OptionalInt`,
+    /// `java.util.OptionalLong` and `java.util.OptionalDouble`
+    /// values returned from java will be converted to the
+    /// contained value or `null` if the optional is empty.
+    /// Otherwise, these values will be returned as java objects.
+    /// Javascript values and `null` are always accepted as
+    /// optional parameters and wrapped using `Optional.ofNullable`.
+    ///
+    /// ## Example
+    /// ```ts
+    /// import { config, clearClassProxies, importClass } from 'java-bridge';
+    ///
+    /// config.unwrapOptionals = true;
+    /// clearClassProxies();
+    ///
+    /// const Optional = importClass('java.util.Optional');
+    /// Optional.ofSync('test'); // 'test'
+    /// Optional.emptySync(); // null
+    /// ```
+    ///
+    /// @since 2.9.0
+    /// @param value whether to unwrap java optionals
+    #[napi(setter, ts_args_type = "value: boolean | undefined | null")]
+    pub fn set_unwrap_optionals(&self, value: Option<bool>) {
+        Config::get().unwrap_optionals = value;
+    }
+
+    /// Get whether java dates are converted to javascript dates.
+    ///
+    /// @since 2.9.0
+    #[napi(getter)]
+    pub fn get_convert_dates(&self) -> Option<bool> {
+        Config::get().convert_dates
+    }
+
+    /// If true, `java.util.Date` and `java.time.Instant`
+    /// values returned from java will be converted to
+    /// javascript `Date`s.
+    /// Otherwise, these values will be returned as java objects.
+    /// Javascript `Date`s are always accepted as `java.util.Date`,
+    /// `java.time.Instant`, `java.time.LocalDateTime`,
+    /// `java.time.ZonedDateTime` and `long` parameters.
+    ///
+    /// ## Example
+    /// ```ts
+    /// import { config, clearClassProxies, importClass } from 'java-bridge';
+    ///
+    /// config.convertDates = true;
+    /// clearClassProxies();
+    ///
+    /// const Instant = importClass('java.time.Instant');
+    /// Instant.ofEpochMilliSync(0); // new Date(0)
+    /// ```
+    ///
+    /// @since 2.9.0
+    /// @param value whether to convert java dates
+    #[napi(setter, ts_args_type = "value: boolean | undefined | null")]
+    pub fn set_convert_dates(&self, value: Option<bool>) {
+        Config::get().convert_dates = value;
+    }
+
+    /// Get the time zone used for converting javascript dates.
+    ///
+    /// @since 2.9.0
+    #[napi(getter)]
+    pub fn get_time_zone(&self) -> Option<String> {
+        Config::get().time_zone.clone()
+    }
+
+    /// Set the time zone id used when converting javascript `Date`s
+    /// to `java.time.LocalDateTime` and `java.time.ZonedDateTime`.
+    /// Pass `null` to use the system default time zone of the jvm.
+    ///
+    /// ## Example
+    /// ```ts
+    /// import { config, clearClassProxies } from 'java-bridge';
+    ///
+    /// config.timeZone = 'UTC';
+    /// clearClassProxies();
+    /// ```
+    ///
+    /// @since 2.9.0
+    /// @param value the time zone id to use
+    #[napi(setter, ts_args_type = "value: string | undefined | null")]
+    pub fn set_time_zone(&self, value: Option<String>) {
+        Config::get().time_zone = value;
+    }
+
+    /// Get whether java big numbers are converted to javascript values.
+    ///
+    /// @since 2.9.0
+    #[napi(getter)]
+    pub fn get_convert_big_numbers(&self) -> Option<bool> {
+        Config::get().convert_big_numbers
+    }
+
+    /// If true, `java.math.BigInteger` values returned from java
+    /// will be converted to `BigInt`s and `java.math.BigDecimal`
+    /// values will be converted depending on the {@link bigDecimalMode}.
+    /// Otherwise, these values will be returned as java objects.
+    /// `BigInt`s and integral numbers are always accepted as `BigInteger`
+    /// parameters, `BigDecimal` parameters additionally accept numbers,
+    /// decimal strings and objects in the form `{ unscaled, scale }`.
+    ///
+    /// ## Example
+    /// ```ts
+    /// import { config, clearClassProxies, importClass } from 'java-bridge';
+    ///
+    /// config.convertBigNumbers = true;
+    /// clearClassProxies();
+    ///
+    /// const BigInteger = importClass('java.math.BigInteger');
+    /// BigInteger.valueOfSync(5); // 5n
+    ///
+    /// const BigDecimal = importClass('java.math.BigDecimal');
+    /// BigDecimal.valueOfSync(5, 2); // '0.05'
+    /// ```
+    ///
+    /// @since 2.9.0
+    /// @param value whether to convert java big numbers
+    #[napi(setter, ts_args_type = "value: boolean | undefined | null")]
+    pub fn set_convert_big_numbers(&self, value: Option<bool>) {
+        Config::get().convert_big_numbers = value;
+    }
+
+    /// Get how `java.math.BigDecimal` values are converted to javascript values.
+    ///
+    /// @since 2.9.0
+    #[napi(getter, ts_return_type = "'string' | 'object' | undefined")]
+    pub fn get_big_decimal_mode(&self) -> Option<BigDecimalMode> {
+        Config::get().big_decimal_mode
+    }
+
+    /// Set how `java.math.BigDecimal` values are converted to javascript
+    /// values if {@link convertBigNumbers} is set. `'string'` returns
+    /// decimal strings without an exponent and `'object'` returns objects
+    /// in the form `{ unscaled, scale }` where `unscaled` is a `BigInt`.
+    /// If not set, `BigDecimal` values are converted to strings.
+    ///
+    /// ## Example
+    /// ```ts
+    /// import { config, clearClassProxies, importClass } from 'java-bridge';
+    ///
+    /// config.convertBigNumbers = true;
+    /// config.bigDecimalMode = 'object';
+    /// clearClassProxies();
+    ///
+    /// const BigDecimal = importClass('java.math.BigDecimal');
+    /// BigDecimal.valueOfSync(5, 2); // { unscaled: 5n, scale: 2 }
+    /// ```
+    ///
+    /// @since 2.9.0
+    /// @param value the big decimal mode to use
+    #[napi(setter, ts_args_type = "value: 'string' | 'object' | undefined | null")]
+    pub fn set_big_decimal_mode(&self, value: Option<BigDecimalMode>) {
+        Config::get().big_decimal_mode = value;
+    }
+
+    /// Get whether primitive java arrays are converted to typed arrays.
+    ///
+    /// @since 2.9.0
+    #[napi(getter)]
+    pub fn get_typed_arrays(&self) -> Option<bool> {
+        Config::get().typed_arrays
+    }
+
+    /// If true, `int[]`, `long[]`, `short[]`, `double[]`, `float[]`
+    /// and `char[]` values returned from java will be converted to
+    /// `Int32Array`s, `BigInt64Array`s, `Int16Array`s, `Float64Array`s,
+    /// `Float32Array`s and `Uint16Array`s.
+    /// Otherwise, these values will be converted to javascript arrays.
+    /// `byte[]` values are always converted to `Buffer`s.
+    /// Typed arrays of these types, `Uint8Array`s and `Int8Array`s
+    /// are always accepted as primitive array parameters.
+    ///
+    /// ## Example
+    /// ```ts
+    /// import { config, clearClassProxies, importClass } from 'java-bridge';
+    ///
+    /// config.typedArrays = true;
+    /// clearClassProxies();
+    ///
+    /// const Arrays = importClass('java.util.Arrays');
+    /// Arrays.copyOfSync(new Int32Array([1, 2]), 3); // Int32Array [1, 2, 0]
+    /// ```
+    ///
+    /// @since 2.9.0
+    /// @param value whether to convert primitive java arrays to typed arrays
+    #[napi(setter, ts_args_type = "value: boolean | undefined | null")]
+    pub fn set_typed_arrays(&self, value: Option<bool>) {
+        Config::get().typed_arrays = value;
+    }
+
+    /// Get whether arrays passed to java are updated after the call.
+    ///
+    /// @since 2.9.0
+    #[napi(getter)]
+    pub fn get_write_back_arrays(&self) -> Option<bool> {
+        Config::get().write_back_arrays
+    }
+
+    /// If true, the contents of javascript arrays and typed arrays
+    /// passed to java methods or constructors as java arrays will
+    /// be updated with the contents of the java arrays after the call,
+    /// making changes made by java visible to javascript.
+    /// Otherwise, only arrays wrapped using {@link out} will be updated.
+    ///
+    /// ## Example
+    /// ```ts
+    /// import { config, clearClassProxies, importClass } from 'java-bridge';
+    ///
+    /// config.writeBackArrays = true;
+    /// clearClassProxies();
+    ///
+    /// const Arrays = importClass('java.util.Arrays');
+    /// const arr = new Int32Array([3, 1, 2]);
+    /// Arrays.sortSync(arr);
+    /// arr; // Int32Array [1, 2, 3]
+    /// ```
+    ///
+    /// @since 2.9.0
+    /// @param value whether to update arrays passed to java
+    #[napi(setter, ts_args_type = "value: boolean | undefined | null")]
+    pub fn set_write_back_arrays(&self, value: Option<bool>) {
+        Config::get().write_back_arrays = value;
+    }
+
+    /// Get whether the creation stacks of interface proxies are recorded.
+    ///
+    /// @since 2.9.0
+    #[napi(getter)]
+    pub fn get_capture_proxy_stacks(&self) -> Option<bool> {
+        Config::get().capture_proxy_stacks
+    }
+
+    /// If true, the javascript stack trace at the creation of
+    /// each interface proxy is recorded and returned by
+    /// `proxies.list`, which helps finding leaked proxies.
+    /// This does not apply to proxies created before setting it.
+    /// Default is false.
+    ///
+    /// ## Example
+    /// ```ts
+    /// import { config, newProxy, proxies } from 'java-bridge';
+    ///
+    /// config.captureProxyStacks = true;
+    /// const proxy = newProxy('java.lang.Runnable', { run: () => {} });
+    /// proxies.list()[0].creationStack; // 'Error: Interface proxy created\n    at ...'
+    /// ```
+    ///
+    /// @since 2.9.0
+    /// @param value whether to record the creation stacks of interface proxies
+    #[napi(setter, ts_args_type = "value: boolean | undefined | null")]
+    pub fn set_capture_proxy_stacks(&self, value: Option<bool>) {
+        Config::get().capture_proxy_stacks = value;
+    }
+
     /// Override the whole config.
     /// If you want to change only a single field, use the static setters instead.
     ///
@@ -0,0 +1,393 @@
+use crate::node::config::Config;
+use crate::node::extensions::java_call_result_ext::ToNapiValue;
+use crate::node::extensions::java_type_ext::NapiToJava;
+use crate::node::helpers::napi_error::{MapToNapiError, StrIntoNapiError};
+use crate::node::interface_proxy::js_error::JsError;
+use crate::node::util::helpers::{call_async_method_with_resolver, ResultType};
+use java_rs::java_call_result::JavaCallResult;
+use java_rs::java_env::JavaEnv;
+use java_rs::java_type::JavaType;
+use java_rs::java_vm::JavaVM;
+use java_rs::objects::args::AsJavaArg;
+use java_rs::objects::class::JavaClass;
+use java_rs::objects::java_object::JavaObject;
+use java_rs::objects::object::{GlobalJavaObject, LocalJavaObject};
+use java_rs::objects::value::{JavaLong, JavaNull};
+use java_rs::sys;
+use java_rs::traits::IsInstanceOf;
+use lazy_static::lazy_static;
+use napi::{CallContext, Env, JsDeferred, JsFunction, JsObject, JsUnknown, ValueType};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+const FUTURE_CALLBACK_CLASS: &str = "io.github.markusjx.bridge.FutureCallback";
+
+type FutureResolver = Box<dyn FnOnce(Env) -> napi::Result<JsUnknown> + Send>;
+
+/// A promise waiting for a `java.util.concurrent.CompletionStage` to complete.
+struct PendingFuture {
+    deferred: JsDeferred<JsUnknown, FutureResolver>,
+    vm: JavaVM,
+    config: Config,
+}
+
+lazy_static! {
+    static ref PENDING_FUTURES: Mutex<HashMap<usize, PendingFuture>> = Mutex::new(HashMap::new());
+}
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+#[no_mangle]
+#[allow(non_snake_case, dead_code)]
+pub extern "system" fn Java_io_github_markusjx_bridge_FutureCallback_complete(
+    _env: *mut sys::JNIEnv,
+    _: sys::jobject,
+    id: sys::jlong,
+    result: sys::jobject,
+    error: sys::jthrowable,
+) {
+    let Some(PendingFuture {
+        deferred,
+        vm,
+        config,
+    }) = PENDING_FUTURES.lock().unwrap().remove(&(id as usize))
+    else {
+        return;
+    };
+
+    // The thread is already attached, attaching it through the
+    // vm makes sure the created objects use the right class loader
+    let res = unsafe { convert_result(&vm, result, error) };
+    deferred.resolve(Box::new(move |env| {
+        let res = res.map_napi_err(Some(env))?;
+        let j_env = vm.attach_thread().map_napi_err(Some(env))?;
+        res.to_napi_value(&j_env, &env, &config)
+            .map_napi_err(Some(env))
+    }));
+}
+
+#[no_mangle]
+#[allow(non_snake_case, dead_code)]
+pub extern "system" fn Java_io_github_markusjx_bridge_FutureCallback_callbackCollected(
+    _env: *mut sys::JNIEnv,
+    _: sys::jclass,
+    id: sys::jlong,
+) {
+    reject_pending(
+        id as usize,
+        "The java future was garbage collected without being completed",
+    );
+}
+
+/// Remove the pending future with the id `id` and reject its promise.
+/// Does nothing if the future has already been completed.
+fn reject_pending(id: usize, reason: &str) {
+    let pending = PENDING_FUTURES.lock().unwrap().remove(&id);
+    if let Some(pending) = pending {
+        pending.deferred.reject(reason.into_napi_err());
+    }
+}
+
+unsafe fn convert_result(
+    vm: &JavaVM,
+    result: sys::jobject,
+    error: sys::jthrowable,
+) -> ResultType<JavaCallResult> {
+    let env = vm.attach_thread()?;
+    if !error.is_null() {
+        let throwable = LocalJavaObject::from_raw(error, &env, None);
+        Err(env
+            .throwable_to_error(JavaObject::from(&throwable))
+            .unwrap_or_else(|e| e))
+    } else if result.is_null() {
+        Ok(JavaCallResult::Null)
+    } else {
+        Ok(JavaCallResult::Object {
+            object: GlobalJavaObject::try_from(LocalJavaObject::from_raw(result, &env, None))?,
+            signature: JavaType::object(),
+        })
+    }
+}
+
+/// Check if `object` is a `java.util.concurrent.Future`
+/// or a `java.util.concurrent.CompletionStage`.
+pub fn is_future(object: &GlobalJavaObject) -> ResultType<bool> {
+    Ok(
+        object.is_instance_of("java/util/concurrent/CompletionStage")?
+            || object.is_instance_of("java/util/concurrent/Future")?,
+    )
+}
+
+/// Cancel `future` using `FutureCallback.cancel`.
+fn cancel(vm: &JavaVM, future: &GlobalJavaObject) -> ResultType<()> {
+    let env = vm.attach_thread()?;
+    let class = JavaClass::by_java_name(FUTURE_CALLBACK_CLASS.into(), &env)?;
+    class
+        .get_static_void_method("cancel", "(Ljava/lang/Object;)V")?
+        .call(&[future.as_arg()])
+}
+
+/// Cancel `future` and reject the promise of the pending
+/// completion stage with the id `pending`, if set. The stage
+/// may not complete once cancelled, e.g. if it can't be
+/// converted to a `java.util.concurrent.CompletableFuture`.
+fn cancel_pending(
+    vm: &JavaVM,
+    future: &GlobalJavaObject,
+    pending: Option<usize>,
+) -> ResultType<()> {
+    let res = cancel(vm, future);
+    if let Some(id) = pending {
+        reject_pending(id, "The java future was cancelled");
+    }
+
+    res
+}
+
+/// Cancel `future` once `signal` is aborted.
+/// If `signal` is already aborted, `future` is cancelled immediately.
+fn cancel_on_abort(
+    env: &Env,
+    vm: &JavaVM,
+    future: &GlobalJavaObject,
+    pending: Option<usize>,
+    signal: JsObject,
+) -> napi::Result<()> {
+    if signal.get_named_property::<bool>("aborted")? {
+        return cancel_pending(vm, future, pending).map_napi_err(Some(*env));
+    }
+
+    let listener_vm = vm.clone();
+    let listener_future = future.clone();
+    let listener = env.create_function_from_closure("abort", move |ctx: CallContext| {
+        cancel_pending(&listener_vm, &listener_future, pending).map_napi_err(Some(*ctx.env))?;
+        ctx.env.get_undefined()
+    })?;
+
+    let mut options = env.create_object()?;
+    options.set_named_property("once", true)?;
+
+    signal
+        .get_named_property::<JsFunction>("addEventListener")?
+        .call(
+            Some(&signal),
+            &[
+                env.create_string("abort")?.into_unknown(),
+                listener.into_unknown(),
+                options.into_unknown(),
+            ],
+        )?;
+
+    Ok(())
+}
+
+/// Convert a `java.util.concurrent.CompletionStage` or
+/// `java.util.concurrent.Future` to a javascript promise.
+///
+/// Completion stages resolve the promise using a callback
+/// registered on the java side, other futures are waited
+/// for on a background thread. If the future completes
+/// exceptionally, the promise is rejected with an error
+/// whose `cause` is the java throwable. If `signal` is
+/// aborted, the future is cancelled.
+pub fn future_to_promise(
+    env: &Env,
+    vm: &JavaVM,
+    future: GlobalJavaObject,
+    config: &Config,
+    signal: Option<JsObject>,
+) -> napi::Result<JsObject> {
+    let mut pending = None;
+    let promise = if future
+        .is_instance_of("java/util/concurrent/CompletionStage")
+        .map_napi_err(Some(*env))?
+    {
+        let (deferred, promise) = env.create_deferred::<JsUnknown, FutureResolver>()?;
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        PENDING_FUTURES.lock().unwrap().insert(
+            id,
+            PendingFuture {
+                deferred,
+                vm: vm.clone(),
+                config: config.clone(),
+            },
+        );
+
+        // The callback may be invoked immediately if the stage
+        // is already completed, the lock must not be held here
+        if let Err(e) = when_complete(vm, &future, id) {
+            PENDING_FUTURES.lock().unwrap().remove(&id);
+            return Err(e).map_napi_err(Some(*env));
+        }
+
+        pending = Some(id);
+        promise
+    } else {
+        let future_vm = vm.clone();
+        let resolver_vm = vm.clone();
+        let config = config.clone();
+        let waited_future = future.clone();
+
+        call_async_method_with_resolver(
+            *env,
+            true,
+            move || {
+                let env = future_vm.attach_thread()?;
+                let class = JavaClass::by_java_name(FUTURE_CALLBACK_CLASS.into(), &env)?;
+                let result = class
+                    .get_static_object_method(
+                        "awaitFuture",
+                        "(Ljava/util/concurrent/Future;)Ljava/lang/Object;",
+                    )?
+                    .call(&[waited_future.as_arg()])?;
+
+                Ok(match result {
+                    Some(object) => JavaCallResult::Object {
+                        object: GlobalJavaObject::try_from(object)?,
+                        signature: JavaType::object(),
+                    },
+                    None => JavaCallResult::Null,
+                })
+            },
+            move |env, res| {
+                let j_env = resolver_vm.attach_thread().map_napi_err(Some(*env))?;
+                res.to_napi_value(&j_env, env, &config)
+                    .map_napi_err(Some(*env))
+            },
+        )?
+    };
+
+    if let Some(signal) = signal {
+        cancel_on_abort(env, vm, &future, pending, signal)?;
+    }
+
+    Ok(promise)
+}
+
+fn when_complete(vm: &JavaVM, future: &GlobalJavaObject, id: usize) -> ResultType<()> {
+    let env = vm.attach_thread()?;
+    let class = JavaClass::by_java_name(FUTURE_CALLBACK_CLASS.into(), &env)?;
+    class
+        .get_static_void_method("whenComplete", "(Ljava/util/concurrent/CompletionStage;J)V")?
+        .call(&[future.as_arg(), JavaLong::new(id as _).as_arg()])
+}
+
+/// Check if javascript promises may be converted to a value of type `java_type`.
+pub fn accepts_promise(java_type: &JavaType) -> bool {
+    matches!(
+        java_type.to_string().as_str(),
+        "java.util.concurrent.CompletableFuture"
+            | "java.util.concurrent.CompletionStage"
+            | "java.util.concurrent.Future"
+    )
+}
+
+/// Check if `value` is a promise or an object with a `then` method.
+pub fn is_thenable(value: &JsUnknown) -> napi::Result<bool> {
+    if value.get_type()? != ValueType::Object {
+        return Ok(false);
+    } else if value.is_promise()? {
+        return Ok(true);
+    }
+
+    let object = unsafe { value.cast::<JsObject>() };
+    let then: JsUnknown = object.get_named_property("then")?;
+    Ok(then.get_type()? == ValueType::Function)
+}
+
+/// Complete `future` with the javascript value `value`.
+/// The value is converted the same way as interface proxy return values.
+/// If the value cannot be converted, the future is completed exceptionally.
+fn complete_future(
+    env: &Env,
+    vm: &JavaVM,
+    future: &GlobalJavaObject,
+    value: JsUnknown,
+    config: &Config,
+) -> ResultType<()> {
+    let j_env = vm.attach_thread()?;
+    let class = JavaClass::by_name("java/util/concurrent/CompletableFuture", &j_env)?;
+
+    match JavaType::object().convert_to_java_object(&j_env, env, value, config) {
+        Ok(value) => {
+            let null = JavaNull;
+            class
+                .get_boolean_method("complete", "(Ljava/lang/Object;)Z")?
+                .call(
+                    JavaObject::from(future),
+                    &[value.as_ref().map(|v| v.as_arg()).unwrap_or(null.as_arg())],
+                )?;
+        }
+        Err(err) => {
+            complete_future_exceptionally(vm, future, JsError::new(err.to_string(), vec![]))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Complete `future` exceptionally with a java exception created from `error`.
+fn complete_future_exceptionally(
+    vm: &JavaVM,
+    future: &GlobalJavaObject,
+    error: JsError,
+) -> ResultType<()> {
+    let j_env = vm.attach_thread()?;
+    let class = JavaClass::by_name("java/util/concurrent/CompletableFuture", &j_env)?;
+    let throwable = error.to_throwable(&j_env)?;
+
+    class
+        .get_boolean_method("completeExceptionally", "(Ljava/lang/Throwable;)Z")?
+        .call(JavaObject::from(future), &[throwable.as_arg()])?;
+
+    Ok(())
+}
+
+/// Convert a javascript promise or thenable to a `java.util.concurrent.CompletableFuture`.
+/// The future is completed once the promise is resolved, or completed
+/// exceptionally with a `JavascriptException` once the promise is rejected.
+pub fn promise_to_future<'a>(
+    env: &'a JavaEnv<'a>,
+    node_env: &Env,
+    value: JsUnknown,
+    config: &Config,
+) -> ResultType<JavaObject<'a>> {
+    let class = JavaClass::by_name("java/util/concurrent/CompletableFuture", env)?;
+    let future = GlobalJavaObject::try_from(class.get_constructor("()V")?.new_instance(env, &[])?)?;
+    let vm = env.get_java_vm()?;
+
+    let fulfilled_vm = vm.clone();
+    let fulfilled_future = future.clone();
+    let fulfilled_config = config.clone();
+    let on_fulfilled = node_env.create_function_from_closure("onFulfilled", move |ctx| {
+        complete_future(
+            ctx.env,
+            &fulfilled_vm,
+            &fulfilled_future,
+            ctx.get::<JsUnknown>(0)?,
+            &fulfilled_config,
+        )
+        .map_napi_err(Some(*ctx.env))?;
+        ctx.env.get_undefined()
+    })?;
+
+    let rejected_future = future.clone();
+    let on_rejected = node_env.create_function_from_closure("onRejected", move |ctx| {
+        complete_future_exceptionally(
+            &vm,
+            &rejected_future,
+            JsError::from_js(ctx.get::<JsUnknown>(0)?)?,
+        )
+        .map_napi_err(Some(*ctx.env))?;
+        ctx.env.get_undefined()
+    })?;
+
+    let promise = value.coerce_to_object()?;
+    promise.get_named_property::<JsFunction>("then")?.call(
+        Some(&promise),
+        &[on_fulfilled.into_unknown(), on_rejected.into_unknown()],
+    )?;
+
+    Ok(JavaObject::from(future))
+}
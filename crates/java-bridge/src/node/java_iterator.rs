@@ -0,0 +1,383 @@
+use crate::node::extensions::java_call_result_ext::ToNapiValue;
+use crate::node::helpers::napi_error::MapToNapiError;
+use crate::node::java_class_proxy::JavaClassProxy;
+use crate::node::util::helpers::{call_async_method_with_resolver, ResultType};
+use java_rs::java_call_result::JavaCallResult;
+use java_rs::java_env::JavaEnv;
+use java_rs::java_type::JavaType;
+use java_rs::java_vm::JavaVM;
+use java_rs::objects::class::{GlobalJavaClass, JavaClass};
+use java_rs::objects::java_object::JavaObject;
+use java_rs::objects::object::GlobalJavaObject;
+use napi::{CallContext, Env, JsFunction, JsObject, JsUnknown};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// The number of elements fetched at once by async iterators.
+const BATCH_SIZE: usize = 64;
+
+/// How instances of a java class can be iterated.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum IterableKind {
+    /// The class implements `java.lang.Iterable`,
+    /// a new iterator is created for every iteration.
+    Iterable,
+    /// The class implements `java.util.Iterator`,
+    /// the instance itself is iterated.
+    Iterator,
+    /// The class implements `java.util.stream.BaseStream`,
+    /// the stream is consumed by the iteration and
+    /// closed once the iteration ends.
+    /// Streams can only be iterated asynchronously.
+    Stream,
+}
+
+impl IterableKind {
+    /// Get how instances of `class` can be iterated.
+    /// Returns `None` if the class is neither iterable nor an iterator.
+    pub fn of_class(env: &JavaEnv, class: &GlobalJavaClass) -> ResultType<Option<Self>> {
+        let class = JavaClass::from_global(class, env);
+        if JavaClass::by_name("java/lang/Iterable", env)?.is_assignable_from(&class)? {
+            Ok(Some(Self::Iterable))
+        } else if JavaClass::by_name("java/util/Iterator", env)?.is_assignable_from(&class)? {
+            Ok(Some(Self::Iterator))
+        } else if JavaClass::by_name("java/util/stream/BaseStream", env)?
+            .is_assignable_from(&class)?
+        {
+            Ok(Some(Self::Stream))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get the `java.util.Iterator` used to iterate `object`.
+    fn get_iterator(&self, vm: &JavaVM, object: &GlobalJavaObject) -> ResultType<GlobalJavaObject> {
+        match self {
+            Self::Iterator => Ok(object.clone()),
+            Self::Iterable | Self::Stream => {
+                let env = vm.attach_thread()?;
+                let class = JavaClass::by_name(
+                    if *self == Self::Stream {
+                        "java/util/stream/BaseStream"
+                    } else {
+                        "java/lang/Iterable"
+                    },
+                    &env,
+                )?;
+                let iterator = class
+                    .get_object_method("iterator", "()Ljava/util/Iterator;")?
+                    .call(JavaObject::from(object), &[])?
+                    .ok_or("iterator() returned null".to_string())?;
+
+                GlobalJavaObject::try_from(iterator)
+            }
+        }
+    }
+}
+
+/// Get up to `count` elements from `iterator`.
+/// Also returns whether the iterator has no more elements.
+fn next_elements(
+    vm: &JavaVM,
+    iterator: &GlobalJavaObject,
+    count: usize,
+) -> ResultType<(Vec<JavaCallResult>, bool)> {
+    let env = vm.attach_thread()?;
+    let class = JavaClass::by_name("java/util/Iterator", &env)?;
+    let has_next = class.get_boolean_method("hasNext", "()Z")?;
+    let next = class.get_object_method("next", "()Ljava/lang/Object;")?;
+
+    let mut res = Vec::with_capacity(count);
+    while res.len() < count {
+        if !has_next.call(JavaObject::from(iterator), &[])? {
+            return Ok((res, true));
+        }
+
+        res.push(match next.call(JavaObject::from(iterator), &[])? {
+            Some(object) => JavaCallResult::Object {
+                object: GlobalJavaObject::try_from(object)?,
+                signature: JavaType::object(),
+            },
+            None => JavaCallResult::Null,
+        });
+    }
+
+    Ok((res, false))
+}
+
+/// Close `stream` using `java.util.stream.BaseStream.close()`.
+fn close_stream(vm: &JavaVM, stream: &GlobalJavaObject) -> ResultType<()> {
+    let env = vm.attach_thread()?;
+    let class = JavaClass::by_name("java/util/stream/BaseStream", &env)?;
+    class
+        .get_void_method("close", "()V")?
+        .call(JavaObject::from(stream), &[])
+}
+
+fn iterator_result(
+    env: &Env,
+    proxy: &JavaClassProxy,
+    value: Option<JavaCallResult>,
+) -> napi::Result<JsObject> {
+    let mut res = env.create_object()?;
+    res.set_named_property("done", env.get_boolean(value.is_none())?)?;
+
+    if let Some(value) = value {
+        let j_env = proxy.vm.attach_thread().map_napi_err(Some(*env))?;
+        res.set_named_property(
+            "value",
+            value
+                .to_napi_value(&j_env, env, &proxy.config)
+                .map_napi_err(Some(*env))?,
+        )?;
+    } else {
+        res.set_named_property("value", env.get_undefined()?)?;
+    }
+
+    Ok(res)
+}
+
+fn resolved_promise(env: &Env, value: JsObject) -> napi::Result<JsObject> {
+    let promise = env
+        .get_global()?
+        .get_named_property::<JsFunction>("Promise")?
+        .coerce_to_object()?;
+
+    promise
+        .get_named_property::<JsFunction>("resolve")?
+        .call(Some(&promise), &[value])?
+        .coerce_to_object()
+}
+
+fn get_symbol(env: &Env, name: &str) -> napi::Result<JsUnknown> {
+    env.get_global()?
+        .get_named_property::<JsFunction>("Symbol")?
+        .coerce_to_object()?
+        .get_named_property(name)
+}
+
+/// Create a javascript iterator over the elements of `iterator`.
+/// Every call to `next()` fetches a single element synchronously.
+fn create_iterator(
+    env: &Env,
+    proxy: Arc<JavaClassProxy>,
+    iterator: GlobalJavaObject,
+) -> napi::Result<JsObject> {
+    let mut res = env.create_object()?;
+    res.set_named_property(
+        "next",
+        env.create_function_from_closure("next", move |ctx: CallContext| {
+            let (mut values, _) =
+                next_elements(&proxy.vm, &iterator, 1).map_napi_err(Some(*ctx.env))?;
+            iterator_result(ctx.env, &proxy, values.pop())
+        })?,
+    )?;
+    res.set_property(
+        get_symbol(env, "iterator")?,
+        env.create_function_from_closure("iterator", |ctx: CallContext| ctx.this::<JsObject>())?,
+    )?;
+
+    Ok(res)
+}
+
+#[derive(Default)]
+struct AsyncIteratorState {
+    buffer: VecDeque<JavaCallResult>,
+    /// Whether no more elements will be fetched
+    done: bool,
+    /// Whether a batch is currently being fetched
+    fetching: bool,
+    /// Whether the stream has been closed
+    closed: bool,
+}
+
+impl AsyncIteratorState {
+    /// Check if the stream should be closed now. This is the case once
+    /// the iteration is done and no batch is being fetched anymore.
+    /// Only returns true once.
+    fn should_close(&mut self) -> bool {
+        if self.done && !self.fetching && !self.closed {
+            self.closed = true;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Fetch the next batch of elements of `iterator` into `state`, unless
+/// elements are left or the iteration is done, and return the next element.
+/// The state is not locked while the elements are fetched, as `next()`
+/// and `return()` lock it on the node thread. Fetches are serialized by
+/// `fetch_lock` instead. If fetching fails, the iteration ends.
+fn fetch_next(
+    vm: &JavaVM,
+    iterator: &GlobalJavaObject,
+    stream: Option<&GlobalJavaObject>,
+    state: &Mutex<AsyncIteratorState>,
+    fetch_lock: &Mutex<()>,
+) -> ResultType<Option<JavaCallResult>> {
+    let _fetch = fetch_lock.lock().unwrap();
+    {
+        let mut current = state.lock().unwrap();
+        if !current.buffer.is_empty() || current.done {
+            return Ok(current.buffer.pop_front());
+        }
+
+        current.fetching = true;
+    }
+
+    let fetched = next_elements(vm, iterator, BATCH_SIZE);
+
+    let mut current = state.lock().unwrap();
+    current.fetching = false;
+    let res = match fetched {
+        // The iteration may have been ended by `return()` in the meantime
+        Ok((values, done)) if !current.done => {
+            current.buffer.extend(values);
+            current.done = done;
+            Ok(current.buffer.pop_front())
+        }
+        Ok(_) => Ok(None),
+        Err(e) => {
+            current.buffer.clear();
+            current.done = true;
+            Err(e)
+        }
+    };
+
+    let close = current.should_close();
+    drop(current);
+
+    match (stream.filter(|_| close), res) {
+        (Some(stream), Ok(value)) => close_stream(vm, stream).map(|_| value),
+        (Some(stream), Err(e)) => {
+            let _ = close_stream(vm, stream);
+            Err(e)
+        }
+        (None, res) => res,
+    }
+}
+
+/// Create a javascript async iterator over the elements of `iterator`.
+/// Elements are fetched in batches on a background thread, calls to
+/// `next()` are resolved from the fetched elements until the batch
+/// has been consumed. If `stream` is set, it is closed once all
+/// elements have been fetched, fetching them failed or `return()`
+/// is called.
+fn create_async_iterator(
+    env: &Env,
+    proxy: Arc<JavaClassProxy>,
+    iterator: GlobalJavaObject,
+    stream: Option<GlobalJavaObject>,
+) -> napi::Result<JsObject> {
+    let state = Arc::new(Mutex::new(AsyncIteratorState::default()));
+    let fetch_lock = Arc::new(Mutex::new(()));
+    let mut res = env.create_object()?;
+
+    let next_state = state.clone();
+    let next_stream = stream.clone();
+    let return_vm = proxy.vm.clone();
+    res.set_named_property(
+        "next",
+        env.create_function_from_closure("next", move |ctx: CallContext| {
+            {
+                let mut current = next_state.lock().unwrap();
+                if !current.buffer.is_empty() || current.done {
+                    let value = current.buffer.pop_front();
+                    drop(current);
+
+                    return resolved_promise(ctx.env, iterator_result(ctx.env, &proxy, value)?);
+                }
+            }
+
+            let state = next_state.clone();
+            let fetch_lock = fetch_lock.clone();
+            let vm = proxy.vm.clone();
+            let iterator = iterator.clone();
+            let stream = next_stream.clone();
+            let resolver_proxy = proxy.clone();
+
+            call_async_method_with_resolver(
+                *ctx.env,
+                proxy.async_java_exception_objects(),
+                move || fetch_next(&vm, &iterator, stream.as_ref(), &state, &fetch_lock),
+                move |env, value| iterator_result(env, &resolver_proxy, value),
+            )
+        })?,
+    )?;
+
+    res.set_named_property(
+        "return",
+        env.create_function_from_closure("return", move |ctx: CallContext| {
+            let mut current = state.lock().unwrap();
+            current.buffer.clear();
+            current.done = true;
+            // If a batch is being fetched, the stream is closed once it has been fetched
+            let close = current.should_close();
+            drop(current);
+
+            if let Some(stream) = stream.as_ref().filter(|_| close) {
+                close_stream(&return_vm, stream).map_napi_err(Some(*ctx.env))?;
+            }
+
+            let mut res = ctx.env.create_object()?;
+            res.set_named_property("done", ctx.env.get_boolean(true)?)?;
+            if ctx.length > 0 {
+                res.set_named_property("value", ctx.get::<JsUnknown>(0)?)?;
+            }
+
+            resolved_promise(ctx.env, res)
+        })?,
+    )?;
+
+    res.set_property(
+        get_symbol(env, "asyncIterator")?,
+        env.create_function_from_closure("asyncIterator", |ctx: CallContext| {
+            ctx.this::<JsObject>()
+        })?,
+    )?;
+
+    Ok(res)
+}
+
+/// Add `Symbol.iterator` and `Symbol.asyncIterator` to `this`,
+/// if the class of `proxy` is iterable or an iterator.
+/// Streams only get `Symbol.asyncIterator`.
+pub fn add_iterators(
+    env: &Env,
+    this: &mut JsObject,
+    proxy: &Arc<JavaClassProxy>,
+    instance: &GlobalJavaObject,
+) -> napi::Result<()> {
+    let Some(kind) = proxy.iterable_kind else {
+        return Ok(());
+    };
+
+    if kind != IterableKind::Stream {
+        let iterator_proxy = proxy.clone();
+        let iterator_instance = instance.clone();
+        this.set_property(
+            get_symbol(env, "iterator")?,
+            env.create_function_from_closure("iterator", move |ctx: CallContext| {
+                let iterator = kind
+                    .get_iterator(&iterator_proxy.vm, &iterator_instance)
+                    .map_napi_err(Some(*ctx.env))?;
+                create_iterator(ctx.env, iterator_proxy.clone(), iterator)
+            })?,
+        )?;
+    }
+
+    let async_proxy = proxy.clone();
+    let async_instance = instance.clone();
+    this.set_property(
+        get_symbol(env, "asyncIterator")?,
+        env.create_function_from_closure("asyncIterator", move |ctx: CallContext| {
+            let iterator = kind
+                .get_iterator(&async_proxy.vm, &async_instance)
+                .map_napi_err(Some(*ctx.env))?;
+            let stream = (kind == IterableKind::Stream).then(|| async_instance.clone());
+            create_async_iterator(ctx.env, async_proxy.clone(), iterator, stream)
+        })?,
+    )
+}
@@ -0,0 +1,59 @@
+use crate::node::helpers::napi_error::StrIntoNapiError;
+use napi::bindgen_prelude::{FromNapiRef, ObjectFinalize};
+use napi::{CallContext, Env, JsUnknown, NapiRaw, Ref, ValueType};
+
+/// An array passed to a java method whose contents
+/// are copied back into the array after the call,
+/// allowing java methods to fill or modify it.
+///
+/// Out arguments can be created using `out`.
+///
+/// @since 2.9.0
+#[napi(custom_finalize)]
+pub struct JavaOutArgument {
+    value: Ref<()>,
+}
+
+#[napi]
+impl JavaOutArgument {
+    pub fn new(env: &Env, value: JsUnknown) -> napi::Result<Self> {
+        if !value.is_array()? && !value.is_typedarray()? {
+            return Err("Only arrays and typed arrays can be used as out arguments".into_napi_err());
+        }
+
+        Ok(Self {
+            value: env.create_reference(value)?,
+        })
+    }
+
+    /// The array wrapped by this out argument.
+    #[napi(getter, ts_return_type = "unknown[] | ArrayBufferView")]
+    pub fn value(&self, env: Env) -> napi::Result<JsUnknown> {
+        env.get_reference_value(&self.value)
+    }
+
+    /// Get the out argument wrapped by `value`, if `value` is an out argument.
+    pub fn from_js(env: &Env, value: &JsUnknown) -> napi::Result<Option<&'static Self>> {
+        if value.get_type()? != ValueType::Object || !Self::instance_of(*env, value)? {
+            return Ok(None);
+        }
+
+        unsafe { Self::from_napi_ref(env.raw(), value.raw()) }.map(Some)
+    }
+}
+
+impl ObjectFinalize for JavaOutArgument {
+    fn finalize(mut self, env: Env) -> napi::Result<()> {
+        self.value.unref(env)?;
+        Ok(())
+    }
+}
+
+/// Get the argument at `index`, unwrapping out arguments.
+pub fn get_argument(ctx: &CallContext, index: usize) -> napi::Result<JsUnknown> {
+    let value: JsUnknown = ctx.get(index)?;
+    match JavaOutArgument::from_js(ctx.env, &value)? {
+        Some(out) => out.value(*ctx.env),
+        None => Ok(value),
+    }
+}
@@ -0,0 +1,220 @@
+use crate::node::extensions::java_type_ext::check_integral;
+use crate::node::helpers::napi_error::{MapToNapiError, StrIntoNapiError};
+use java_rs::java_call_result::JavaCallResult;
+use java_rs::java_env::JavaEnv;
+use java_rs::java_type::{JavaType, Type};
+use java_rs::java_vm::JavaVM;
+use java_rs::objects::java_object::JavaObject;
+use java_rs::objects::object::{GlobalJavaObject, LocalJavaObject};
+use java_rs::traits::ToJavaValue;
+use java_rs::util::helpers::ResultType;
+use napi::bindgen_prelude::FromNapiRef;
+use napi::{Env, JsBigInt, JsUnknown, NapiRaw, ValueType};
+
+/// A javascript value with an explicit java type.
+/// Values wrapped in a type hint are treated as an exact
+/// match for their type when selecting a method overload
+/// and are boxed to their type when passed as objects.
+///
+/// Type hints can be created using the `types` namespace.
+///
+/// @since 2.9.0
+#[napi]
+pub struct JavaTypeHint {
+    vm: JavaVM,
+    java_type: JavaType,
+    value: JavaCallResult,
+}
+
+#[napi]
+impl JavaTypeHint {
+    pub fn new(vm: JavaVM, class_name: String, value: JsUnknown) -> napi::Result<Self> {
+        let java_type = JavaType::new(class_name, true);
+        let value_type = value.get_type()?;
+
+        let value = if value_type == ValueType::Null || value_type == ValueType::Undefined {
+            if java_type.is_primitive() {
+                return Err(
+                    format!("A value of type '{}' cannot be null", java_type).into_napi_err()
+                );
+            }
+
+            JavaCallResult::Null
+        } else if java_type.is_char() {
+            let str = value.coerce_to_string()?;
+            if value_type != ValueType::String || str.utf16_len()? != 1 {
+                return Err(
+                    "A java character must be a string with a single character".into_napi_err()
+                );
+            }
+
+            JavaCallResult::Character(str.into_utf16()?.as_slice()[0])
+        } else if java_type.is_boolean() {
+            if value_type != ValueType::Boolean {
+                return Err("Expected a boolean value".into_napi_err());
+            }
+
+            JavaCallResult::Boolean(value.coerce_to_bool()?.get_value()?)
+        } else if java_type.is_long() && value_type == ValueType::BigInt {
+            let (val, lossless) = unsafe { value.cast::<JsBigInt>() }.get_i64()?;
+            if !lossless {
+                return Err(
+                    "Cannot convert BigInt to 'long': the value is out of range".into_napi_err()
+                );
+            }
+
+            JavaCallResult::Long(val)
+        } else if value_type == ValueType::Number {
+            let number = value.coerce_to_number()?;
+            // The hint selects an exact type, so the value must not change
+            check_integral(number.get_double()?, java_type.type_enum()).map_napi_err(None)?;
+
+            match java_type.type_enum() {
+                Type::Byte | Type::LangByte => JavaCallResult::Byte(number.get_int32()? as i8),
+                Type::Short | Type::LangShort => JavaCallResult::Short(number.get_int32()? as i16),
+                Type::Integer | Type::LangInteger => JavaCallResult::Integer(number.get_int32()?),
+                Type::Long | Type::LangLong => JavaCallResult::Long(number.get_int64()?),
+                Type::Float | Type::LangFloat => JavaCallResult::Float(number.get_double()? as f32),
+                Type::Double | Type::LangDouble => JavaCallResult::Double(number.get_double()?),
+                _ => {
+                    return Err(
+                        format!("Cannot use a number as a value of type '{}'", java_type)
+                            .into_napi_err(),
+                    )
+                }
+            }
+        } else {
+            return Err(format!(
+                "Invalid value supplied for type '{}', only numbers, bigints, strings, booleans or null are allowed",
+                java_type
+            )
+            .into_napi_err());
+        };
+
+        Ok(Self {
+            vm,
+            java_type,
+            value,
+        })
+    }
+
+    /// The java type of this value.
+    #[napi(getter, js_name = "type")]
+    pub fn java_type(&self) -> String {
+        self.java_type.to_string()
+    }
+
+    /// The javascript value wrapped by this type hint.
+    #[napi(getter, ts_return_type = "number | bigint | string | boolean | null")]
+    pub fn value(&self, env: Env) -> napi::Result<JsUnknown> {
+        Ok(match self.value {
+            JavaCallResult::Boolean(b) => env.get_boolean(b)?.into_unknown(),
+            JavaCallResult::Byte(b) => env.create_int32(b as i32)?.into_unknown(),
+            JavaCallResult::Short(s) => env.create_int32(s as i32)?.into_unknown(),
+            JavaCallResult::Integer(i) => env.create_int32(i)?.into_unknown(),
+            JavaCallResult::Long(l) => env.create_bigint_from_i64(l)?.into_unknown()?,
+            JavaCallResult::Float(f) => env.create_double(f as f64)?.into_unknown(),
+            JavaCallResult::Double(d) => env.create_double(d)?.into_unknown(),
+            JavaCallResult::Character(c) => env.create_string_utf16(&[c])?.into_unknown(),
+            _ => env.get_null()?.into_unknown(),
+        })
+    }
+
+    /// Get the type hint wrapped by `value`, if `value` is a type hint.
+    pub fn from_js(env: &Env, value: &JsUnknown) -> napi::Result<Option<&'static Self>> {
+        if value.get_type()? != ValueType::Object || !Self::instance_of(*env, value)? {
+            return Ok(None);
+        }
+
+        unsafe { Self::from_napi_ref(env.raw(), value.raw()) }.map(Some)
+    }
+
+    /// Check if this value can be passed to a parameter of type `java_type`.
+    /// Primitive values exactly match their own type or its boxed type,
+    /// nulls exactly match their own type. If `allow_assignable` is set,
+    /// values also match any type their (boxed) class is assignable to.
+    pub fn matches(&self, java_type: &JavaType, allow_assignable: bool) -> ResultType<bool> {
+        let exact = match self.value {
+            JavaCallResult::Null => self.java_type == *java_type,
+            _ => self.value.get_type() == unboxed_type(java_type.type_enum()),
+        };
+
+        if exact || !allow_assignable || java_type.is_primitive() {
+            return Ok(exact);
+        } else if java_type.is_array() || self.java_type.is_array() {
+            return Ok(false);
+        }
+
+        let env = self.vm.attach_thread()?;
+        let class = java_type.as_class(&env)?;
+        let own_class = self.java_type.as_class(&env)?;
+
+        class.is_assignable_from(&own_class)
+    }
+
+    /// Convert this value to a value of type `java_type`.
+    pub fn to_java_value(&self, java_type: &JavaType, env: &JavaEnv) -> ResultType<JavaCallResult> {
+        self.check_matches(java_type)?;
+        if java_type.is_primitive() {
+            return Ok(self.value.clone());
+        }
+
+        Ok(match self.box_value(env)? {
+            Some(obj) => JavaCallResult::Object {
+                object: GlobalJavaObject::try_from(obj)?,
+                signature: java_type.clone(),
+            },
+            None => JavaCallResult::Null,
+        })
+    }
+
+    /// Convert this value to a (boxed) java object of type `java_type`.
+    pub fn to_java_object<'a>(
+        &self,
+        java_type: &JavaType,
+        env: &'a JavaEnv<'a>,
+    ) -> ResultType<Option<JavaObject<'a>>> {
+        self.check_matches(java_type)?;
+        Ok(self.box_value(env)?.map(JavaObject::from))
+    }
+
+    fn check_matches(&self, java_type: &JavaType) -> ResultType<()> {
+        if self.matches(java_type, true)? {
+            Ok(())
+        } else {
+            Err(format!(
+                "A value of type '{}' cannot be converted to '{}'",
+                self.java_type, java_type
+            )
+            .into())
+        }
+    }
+
+    fn box_value<'a>(&self, env: &'a JavaEnv<'a>) -> ResultType<Option<LocalJavaObject<'a>>> {
+        Ok(Some(match self.value {
+            JavaCallResult::Boolean(b) => LocalJavaObject::from_bool(env, b)?,
+            JavaCallResult::Byte(b) => LocalJavaObject::from_byte(env, b)?,
+            JavaCallResult::Character(c) => LocalJavaObject::from_char(env, c)?,
+            JavaCallResult::Short(s) => LocalJavaObject::from_i16(env, s)?,
+            JavaCallResult::Integer(i) => LocalJavaObject::from_i32(env, i)?,
+            JavaCallResult::Long(l) => LocalJavaObject::from_i64(env, l)?,
+            JavaCallResult::Float(f) => LocalJavaObject::from_f32(env, f)?,
+            JavaCallResult::Double(d) => LocalJavaObject::from_f64(env, d)?,
+            _ => return Ok(None),
+        }))
+    }
+}
+
+fn unboxed_type(type_enum: Type) -> Type {
+    match type_enum {
+        Type::LangBoolean => Type::Boolean,
+        Type::LangByte => Type::Byte,
+        Type::LangCharacter => Type::Character,
+        Type::LangShort => Type::Short,
+        Type::LangInteger => Type::Integer,
+        Type::LangLong => Type::Long,
+        Type::LangFloat => Type::Float,
+        Type::LangDouble => Type::Double,
+        other => other,
+    }
+}
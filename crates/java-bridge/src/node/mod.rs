@@ -8,6 +8,10 @@ mod java;
 mod java_class_instance;
 mod java_class_proxy;
 mod java_config;
+mod java_future;
+mod java_iterator;
 pub mod java_options;
+mod java_out_argument;
+mod java_type_hint;
 mod stdout_redirect;
 pub mod util;
@@ -1,5 +1,7 @@
 use crate::node::extensions::java_call_result_ext::ToNapiValue;
+use crate::node::helpers::arg_convert::{apply_array_write_backs, ArrayWriteBack};
 use crate::node::helpers::napi_error::{MapToNapiError, StrIntoNapiError};
+use crate::node::interface_proxy::function_proxy::FunctionProxies;
 use crate::node::java_class_proxy::JavaClassProxy;
 use glob::glob;
 use java_rs::java_call_result::JavaCallResult;
@@ -77,17 +79,35 @@ pub fn parse_classpath_args(cp: &[String], args: &mut Vec<String>) -> String {
     )
 }
 
-pub fn call_async_method<F>(env: Env, proxy: Arc<JavaClassProxy>, func: F) -> napi::Result<JsObject>
+/// Call `func` on a different thread and convert its result using
+/// the config of `proxy`. The arrays in `write_backs` are updated
+/// once the call has finished, even if it failed.
+pub fn call_async_method<F>(
+    env: Env,
+    proxy: Arc<JavaClassProxy>,
+    write_backs: Vec<ArrayWriteBack>,
+    function_proxies: FunctionProxies,
+    func: F,
+) -> napi::Result<JsObject>
 where
     F: (FnOnce() -> ResultType<JavaCallResult>) + Send + Sync + 'static,
 {
     call_async_method_with_resolver(
         env,
-        proxy.async_java_exception_objects(),
-        func,
+        true,
+        move || Ok(func()),
         move |&mut env, res| {
             let j_env = proxy.vm.attach_thread().map_napi_err(Some(env))?;
-            res.to_napi_value(&j_env, &env).map_napi_err(Some(env))
+            apply_array_write_backs(&env, &j_env, write_backs, &proxy.config)?;
+
+            let error_env = proxy.async_java_exception_objects().then_some(env);
+            let res = res
+                .map_napi_err(error_env)?
+                .to_napi_value(&j_env, &env, &proxy.config)
+                .map_napi_err(Some(env))?;
+
+            drop(function_proxies);
+            Ok(res)
         },
     )
 }
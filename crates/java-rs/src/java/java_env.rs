@@ -9,6 +9,7 @@ use crate::java::util::helpers::{jni_version_to_string, ResultType};
 use crate::java::vm_ptr::JavaVMPtr;
 use crate::objects::args::AsJavaArg;
 use crate::{define_object_to_val_method, sys};
+use std::error::Error;
 use std::sync::{Arc, Mutex};
 
 /// The pointer to a java environment.
@@ -102,6 +103,13 @@ impl<'a> JavaEnv<'a> {
         self.0.throw(object)
     }
 
+    pub fn throwable_to_error(
+        &self,
+        throwable: JavaObject,
+    ) -> ResultType<Box<dyn Error + Send + Sync>> {
+        self.0.throwable_to_error(throwable)
+    }
+
     define_object_to_val_method!(
         object_to_int,
         i32,
@@ -198,6 +198,18 @@ impl<'a> JavaEnvWrapper<'a> {
         }
     }
 
+    /// Convert a java throwable to a [`JavaError`](JavaError)
+    /// containing the throwable, its causes and its stack trace.
+    /// This throws the throwable and clears it again,
+    /// make sure no other exception is pending.
+    pub fn throwable_to_error(
+        &self,
+        throwable: JavaObject,
+    ) -> ResultType<Box<dyn Error + Send + Sync>> {
+        self.throw(throwable);
+        self.get_last_error(file!(), line!(), true, "Failed to convert the throwable")
+    }
+
     /// Check if an error has been thrown inside this environment
     ///
     /// See also [`get_last_error`](Self::get_last_error).
@@ -65,11 +65,14 @@ impl Display for Type {
 /// A wrapper around a java type.
 /// This contains the [`Type`](Type), its internal
 /// signature and possible inner types, when this is an array.
+/// Generic type arguments may be attached to a type, but they
+/// are not part of its signature.
 #[derive(Clone)]
 pub struct JavaType {
     inner: Option<Arc<Mutex<JavaType>>>,
     signature: String,
     type_enum: Type,
+    type_arguments: Vec<JavaType>,
     hash: Option<u64>,
 }
 
@@ -180,6 +183,7 @@ impl JavaType {
             inner,
             signature,
             type_enum,
+            type_arguments: vec![],
             hash: None,
         }
     }
@@ -189,6 +193,7 @@ impl JavaType {
             inner: inner.map(|inner| Arc::new(Mutex::new(inner))),
             signature,
             type_enum,
+            type_arguments: vec![],
             hash: None,
         }
     }
@@ -198,6 +203,7 @@ impl JavaType {
             signature: format!("{}[]", inner.signature),
             inner: Some(Arc::new(Mutex::new(inner))),
             type_enum: Type::Array,
+            type_arguments: vec![],
             hash: None,
         }
     }
@@ -294,6 +300,83 @@ impl JavaType {
         self.inner.clone()
     }
 
+    /// Create a new [`JavaType`](JavaType) from a generic type name
+    /// as returned by `java.lang.reflect.Type.getTypeName()`.
+    /// The type arguments of the type are parsed recursively.
+    /// Wildcards with an upper bound are resolved to their bound,
+    /// any other wildcards and type variables are resolved to
+    /// `java.lang.Object`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use java_rs::java_type::{JavaType, Type};
+    ///
+    /// let t = JavaType::from_generic_name("java.util.Map<java.lang.String, ? extends java.lang.Number>");
+    /// assert_eq!(t.to_string(), "java.util.Map");
+    ///
+    /// let args = t.type_arguments();
+    /// assert_eq!(args[0].type_enum(), Type::String);
+    /// assert_eq!(args[1].to_string(), "java.lang.Number");
+    /// ```
+    pub fn from_generic_name(name: &str) -> Self {
+        let name = name.trim();
+        if let Some(bound) = name.strip_prefix("? extends ") {
+            return Self::from_generic_name(bound);
+        } else if name.starts_with('?') {
+            return Self::object();
+        } else if let Some(inner) = name.strip_suffix("[]") {
+            return Self::array(Self::from_generic_name(inner));
+        }
+
+        let (raw, arguments) = match (name.find('<'), name.rfind('>')) {
+            (Some(start), Some(end)) if start < end => (&name[..start], &name[start + 1..end]),
+            _ => (name, ""),
+        };
+
+        // Primitives only occur as the component type of arrays, e.g. in
+        // `List<int[]>`. Other names without a package are type variables,
+        // as classes in the default package are not used as type arguments.
+        let mut res = Self::new(raw.to_string(), false);
+        if !res.is_primitive() && !raw.contains('.') && !raw.contains('$') {
+            res = Self::object();
+        }
+
+        let mut depth = 0;
+        let mut start = 0;
+        for (i, c) in arguments.char_indices() {
+            match c {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                ',' if depth == 0 => {
+                    res.type_arguments
+                        .push(Self::from_generic_name(&arguments[start..i]));
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+
+        if !arguments.trim().is_empty() {
+            res.type_arguments
+                .push(Self::from_generic_name(&arguments[start..]));
+        }
+
+        res
+    }
+
+    /// Get the generic type arguments of this type.
+    /// This is empty if the type is not generic or
+    /// the type arguments are unknown.
+    pub fn type_arguments(&self) -> &[JavaType] {
+        &self.type_arguments
+    }
+
+    /// Set the generic type arguments of this type.
+    pub fn with_type_arguments(mut self, type_arguments: Vec<JavaType>) -> Self {
+        self.type_arguments = type_arguments;
+        self
+    }
+
     /// Get the type enum of this java type.
     pub fn type_enum(&self) -> Type {
         self.type_enum
@@ -403,6 +486,10 @@ impl JavaType {
         self.type_enum == Type::Array && self.inner.as_ref().unwrap().lock().unwrap().is_byte()
     }
 
+    pub fn is_char_array(&self) -> bool {
+        self.type_enum == Type::Array && self.inner.as_ref().unwrap().lock().unwrap().is_char()
+    }
+
     pub fn is_array(&self) -> bool {
         self.type_enum == Type::Array
     }
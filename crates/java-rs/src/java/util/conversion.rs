@@ -86,6 +86,43 @@ pub fn get_method_parameters(env: &JavaEnv, method: &LocalJavaObject) -> ResultT
     Ok(parameter_types)
 }
 
+/// Get the generic parameter types of a `java.lang.reflect.Method`
+/// or `java.lang.reflect.Constructor`, including their type arguments.
+pub fn get_generic_parameter_types(
+    env: &JavaEnv,
+    executable: &LocalJavaObject,
+) -> ResultType<Vec<JavaType>> {
+    let executable_class = env.find_class("java/lang/reflect/Executable")?;
+    let get_generic_parameter_types = executable_class
+        .get_object_method("getGenericParameterTypes", "()[Ljava/lang/reflect/Type;")?
+        .bind(executable.into());
+    let parameters = JavaObjectArray::from(
+        get_generic_parameter_types
+            .call(&[])?
+            .ok_or("Executable.getGenericParameterTypes() returned null".to_string())?,
+    );
+
+    let type_class = env.find_class("java/lang/reflect/Type")?;
+    let get_type_name = type_class.get_object_method("getTypeName", "()Ljava/lang/String;")?;
+
+    let mut res = vec![];
+    for i in 0..parameters.len()? {
+        let parameter = parameters.get(i)?.ok_or(format!(
+            "The array returned by Executable.getGenericParameterTypes() contained a null value at position {}",
+            i
+        ))?;
+        let name = JavaString::try_from(
+            get_type_name
+                .call(JavaObject::from(&parameter), &[])?
+                .ok_or("Type.getTypeName() returned null".to_string())?,
+        )?;
+
+        res.push(JavaType::from_generic_name(&name.to_string()?));
+    }
+
+    Ok(res)
+}
+
 pub fn get_method_from_signature(
     env: &JavaEnv,
     class_name: String,
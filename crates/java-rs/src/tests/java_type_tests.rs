@@ -0,0 +1,68 @@
+use crate::java_type::{JavaType, Type};
+
+#[test]
+fn parse_generic_type() {
+    let t = JavaType::from_generic_name("java.util.List<java.lang.Long>");
+    assert_eq!(t.to_string(), "java.util.List");
+    assert_eq!(t.type_enum(), Type::Object);
+    assert_eq!(t.type_arguments().len(), 1);
+    assert_eq!(t.type_arguments()[0].type_enum(), Type::LangLong);
+}
+
+#[test]
+fn parse_nested_generic_type() {
+    let t = JavaType::from_generic_name(
+        "java.util.Map<java.lang.String, java.util.List<java.lang.Integer[]>>",
+    );
+    assert_eq!(t.to_string(), "java.util.Map");
+
+    let args = t.type_arguments();
+    assert_eq!(args.len(), 2);
+    assert_eq!(args[0].type_enum(), Type::String);
+    assert_eq!(args[1].to_string(), "java.util.List");
+    assert_eq!(
+        args[1].type_arguments()[0].to_string(),
+        "java.lang.Integer[]"
+    );
+    assert!(args[1].type_arguments()[0].is_array());
+}
+
+#[test]
+fn parse_generic_type_with_wildcards() {
+    let t = JavaType::from_generic_name(
+        "java.util.Map<? extends java.lang.Number, ? super java.lang.Integer>",
+    );
+    let args = t.type_arguments();
+    assert_eq!(args[0].to_string(), "java.lang.Number");
+    assert_eq!(args[1].type_enum(), Type::LangObject);
+
+    let t = JavaType::from_generic_name("java.util.Collection<?>");
+    assert_eq!(t.type_arguments()[0].type_enum(), Type::LangObject);
+}
+
+#[test]
+fn parse_generic_type_with_type_variables() {
+    let t = JavaType::from_generic_name("java.util.List<E>");
+    assert_eq!(t.type_arguments()[0].type_enum(), Type::LangObject);
+
+    let t = JavaType::from_generic_name("java.lang.String");
+    assert!(t.type_arguments().is_empty());
+    assert!(t == JavaType::string());
+}
+
+#[test]
+fn parse_generic_type_with_primitive_arrays() {
+    let t = JavaType::from_generic_name("java.util.List<int[]>");
+    let arg = &t.type_arguments()[0];
+    assert!(arg.is_array());
+    assert_eq!(arg.to_string(), "int[]");
+    assert_eq!(
+        arg.inner().unwrap().lock().unwrap().type_enum(),
+        Type::Integer
+    );
+
+    let t = JavaType::from_generic_name("java.util.Map<java.lang.String, byte[][]>");
+    let arg = &t.type_arguments()[1];
+    assert_eq!(arg.to_string(), "byte[][]");
+    assert_eq!(arg.inner().unwrap().lock().unwrap().to_string(), "byte[]");
+}
@@ -2,6 +2,7 @@ mod array_tests;
 mod basic_tests;
 mod common;
 mod field_tests;
+mod java_type_tests;
 mod method_tests;
 mod object_tests;
 #[cfg(feature = "type_check")]
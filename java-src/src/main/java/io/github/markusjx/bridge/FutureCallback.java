@@ -0,0 +1,107 @@
+package io.github.markusjx.bridge;
+
+import java.lang.ref.Cleaner;
+import java.util.concurrent.CompletionException;
+import java.util.concurrent.CompletionStage;
+import java.util.concurrent.ExecutionException;
+import java.util.concurrent.Future;
+import java.util.function.BiConsumer;
+
+/**
+ * A class for resolving javascript promises once
+ * a java future has been completed.
+ */
+@SuppressWarnings("unused")
+public class FutureCallback implements BiConsumer<Object, Throwable> {
+    /**
+     * The cleaner used to notice callbacks dropped by their stage
+     */
+    private static final Cleaner CLEANER = Cleaner.create();
+
+    /**
+     * The id of the native promise to resolve
+     */
+    private final long id;
+
+    /**
+     * Create a new FutureCallback instance
+     *
+     * @param id the id of the native promise to resolve
+     */
+    private FutureCallback(long id) {
+        this.id = id;
+    }
+
+    /**
+     * Resolve or reject the native promise
+     *
+     * @param id     the id of the native promise
+     * @param result the result of the future
+     * @param error  the error the future was completed with or null
+     */
+    private native void complete(long id, Object result, Throwable error);
+
+    /**
+     * Reject the native promise if it hasn't been resolved yet.
+     * This is called once the callback has been garbage collected,
+     * e.g. if the stage was dropped without being completed.
+     *
+     * @param id the id of the native promise
+     */
+    private static native void callbackCollected(long id);
+
+    /**
+     * Resolve the native promise with the id {@code id}
+     * once {@code stage} has been completed
+     *
+     * @param stage the stage to wait for
+     * @param id    the id of the native promise
+     */
+    public static void whenComplete(CompletionStage<?> stage, long id) {
+        FutureCallback callback = new FutureCallback(id);
+        CLEANER.register(callback, () -> callbackCollected(id));
+        stage.whenComplete(callback);
+    }
+
+    /**
+     * Wait for a future to complete. If the future completed
+     * exceptionally, the cause of the exception is thrown.
+     *
+     * @param future the future to wait for
+     * @return the result of the future
+     * @throws Throwable the error the future was completed with
+     */
+    public static Object awaitFuture(Future<?> future) throws Throwable {
+        try {
+            return future.get();
+        } catch (ExecutionException e) {
+            throw e.getCause() != null ? e.getCause() : e;
+        }
+    }
+
+    /**
+     * Cancel a future. Futures and stages which
+     * do not support cancellation are ignored.
+     *
+     * @param future the future or completion stage to cancel
+     */
+    public static void cancel(Object future) {
+        try {
+            if (future instanceof Future) {
+                ((Future<?>) future).cancel(true);
+            } else if (future instanceof CompletionStage) {
+                ((CompletionStage<?>) future).toCompletableFuture().cancel(true);
+            }
+        } catch (UnsupportedOperationException ignored) {
+        }
+    }
+
+    @Override
+    public void accept(Object result, Throwable error) {
+        if (error instanceof CompletionException && error.getCause() != null) {
+            error = error.getCause();
+        }
+
+        complete(id, result, error);
+    }
+}
@@ -1,5 +1,6 @@
 package io.github.markusjx.bridge;
 
+import java.lang.ref.Cleaner;
 import java.lang.reflect.InvocationHandler;
 import java.lang.reflect.Method;
 import java.util.Arrays;
@@ -11,6 +12,7 @@ import java.util.List;
  */
 @SuppressWarnings("unused")
 public class JavaFunctionCaller implements InvocationHandler {
+    private static final Cleaner CLEANER = Cleaner.create();
     private static final Method EQUALS;
     private static final Method HASH_CODE;
     private static final Method TO_STRING;
@@ -33,32 +35,95 @@ public class JavaFunctionCaller implements InvocationHandler {
      * The pointer to the native proxy class
      */
     private final long ptr;
+    /**
+     * The time in milliseconds to wait for a node.js function to return,
+     * or zero to wait indefinitely
+     */
+    private final long timeoutMs;
+    /**
+     * The functions implementing void methods which
+     * are called without waiting for them to return
+     */
+    private final List<String> nonBlockingMethods;
     /**
      * Whether this caller instance is still valid
      */
-    private boolean valid;
+    private volatile boolean valid;
 
     /**
      * Create a new JavaFunctionCaller instance
      *
      * @param implementedMethods the form the node process implemented functions
      * @param ptr                the pointer to the native proxy class
+     * @param timeoutMs          the time in milliseconds to wait for a node.js
+     *                           function to return, or zero to wait indefinitely
+     * @param nonBlockingMethods the functions implementing void methods which
+     *                           are called without waiting for them to return
      */
-    public JavaFunctionCaller(String[] implementedMethods, long ptr) {
+    public JavaFunctionCaller(String[] implementedMethods, long ptr, long timeoutMs, String[] nonBlockingMethods) {
         this.implementedMethods = Arrays.asList(implementedMethods);
         this.ptr = ptr;
+        this.timeoutMs = timeoutMs;
+        this.nonBlockingMethods = Arrays.asList(nonBlockingMethods);
         this.valid = true;
     }
 
     /**
      * Call a java function from node.js
      *
-     * @param ptr  the pointer to the native proxy class
-     * @param m    the method to call
-     * @param args the function arguments
+     * @param ptr         the pointer to the native proxy class
+     * @param timeoutMs   the time in milliseconds to wait for the function to return,
+     *                    a {@link java.util.concurrent.TimeoutException} is thrown once it expires
+     * @param nonBlocking whether to return immediately without waiting for the function
+     * @param name        the name of the node.js function implementing the method
+     * @param m           the method to call
+     * @param args        the function arguments
      * @return the function return value
      */
-    private native Object callNodeFunction(long ptr, Method m, Object[] args);
+    private native Object callNodeFunction(long ptr, long timeoutMs, boolean nonBlocking, String name, Method m, Object[] args);
+
+    /**
+     * Notify the native proxy class that its proxy instance was garbage collected
+     *
+     * @param ptr the pointer to the native proxy class
+     */
+    private native void proxyCollected(long ptr);
+
+    /**
+     * Remove the native proxy class once the proxy instance using
+     * this caller is no longer reachable. Callers destroyed before
+     * the proxy instance is collected are not removed again.
+     *
+     * @param proxy the proxy instance using this caller
+     */
+    public void registerCleaner(Object proxy) {
+        CLEANER.register(proxy, () -> {
+            if (valid) {
+                valid = false;
+                proxyCollected(ptr);
+            }
+        });
+    }
+
+    /**
+     * Get the name of the node.js function implementing a method.
+     * Functions named after the declaring interface and the method,
+     * e.g. {@code java.lang.AutoCloseable.close}, take precedence
+     * over functions named after the method only.
+     *
+     * @param method the method to get the implementing function of
+     * @return the name of the function or null if the method isn't implemented
+     */
+    private String getImplementationName(Method method) {
+        String qualifiedName = method.getDeclaringClass().getName() + "." + method.getName();
+        if (implementedMethods.contains(qualifiedName)) {
+            return qualifiedName;
+        } else if (implementedMethods.contains(method.getName())) {
+            return method.getName();
+        } else {
+            return null;
+        }
+    }
 
     /**
      * Mark this caller as invalid
@@ -71,6 +136,14 @@ public class JavaFunctionCaller implements InvocationHandler {
     public Object invoke(Object proxy, Method method, Object[] args) throws Throwable {
         if (!valid) {
             throw new IllegalAccessException("The proxy interface isn't valid anymore");
+        }
+
+        // Methods implemented by the node process take precedence,
+        // this allows overriding equals, hashCode and toString
+        String name = getImplementationName(method);
+        if (name != null) {
+            boolean nonBlocking = method.getReturnType() == void.class && nonBlockingMethods.contains(name);
+            return callNodeFunction(ptr, timeoutMs, nonBlocking, name, method, args);
         } else if (method.equals(EQUALS)) {
             return args[0] == proxy;
         } else if (method.equals(HASH_CODE)) {
@@ -81,11 +154,7 @@ public class JavaFunctionCaller implements InvocationHandler {
                     ", ptr=" + ptr +
                     '}';
         } else {
-            if (implementedMethods.contains(method.getName())) {
-                return callNodeFunction(ptr, method, args);
-            } else {
-                throw new NoSuchMethodException("The requested method was not defined by the javascript process");
-            }
+            throw new NoSuchMethodException("The requested method was not defined by the javascript process");
         }
     }
 }
@@ -166,6 +166,114 @@ describe('ArrayListTest', () => {
         expect(list + '').to.equal('[Hello, World]');
     });
 
+    it('Iterate', async () => {
+        const list = new ArrayList!();
+        for (let i = 0; i < 100; i++) {
+            list.addSync(i);
+        }
+
+        const expected = [...Array(100).keys()];
+        expect([...(list as unknown as Iterable<number>)]).to.deep.equal(
+            expected
+        );
+
+        const values: number[] = [];
+        for await (const value of list as unknown as AsyncIterable<number>) {
+            values.push(value);
+        }
+        expect(values).to.deep.equal(expected);
+
+        const iterator = list.iteratorSync() as unknown as Iterable<number>;
+        expect([...iterator]).to.deep.equal(expected);
+        expect([...iterator]).to.be.empty;
+
+        const string = java.importClass('java.lang.String');
+        expect(Symbol.iterator in new string('a')).to.be.false;
+    });
+
+    it('Iterate stream', async () => {
+        const list = new ArrayList!();
+        for (let i = 0; i < 100; i++) {
+            list.addSync(i);
+        }
+
+        const stream = list.streamSync();
+        expect(Symbol.iterator in stream).to.be.false;
+
+        const values: number[] = [];
+        for await (const value of stream as unknown as AsyncIterable<number>) {
+            values.push(value);
+        }
+        expect(values).to.deep.equal([...Array(100).keys()]);
+
+        let closed = false;
+        const onClose = java.newProxy('java.lang.Runnable', {
+            run: () => {
+                closed = true;
+            },
+        });
+
+        const first: number[] = [];
+        try {
+            const iterable = list
+                .streamSync()
+                .onCloseSync(onClose) as unknown as AsyncIterable<number>;
+            for await (const value of iterable) {
+                first.push(value);
+                if (first.length === 3) {
+                    break;
+                }
+            }
+        } finally {
+            onClose.reset();
+        }
+
+        expect(first).to.deep.equal([0, 1, 2]);
+        expect(closed).to.be.true;
+    });
+
+    it('Close a stream which fails', async () => {
+        const list = new ArrayList!();
+        list.addSync(1);
+
+        let closed = 0;
+        const onClose = java.newProxy('java.lang.Runnable', {
+            run: () => {
+                closed++;
+            },
+        });
+        const mapper = java.newProxy('java.util.function.Function', {
+            apply: () => {
+                throw new Error('failed');
+            },
+        });
+
+        try {
+            const iterable = list
+                .streamSync()
+                .mapSync(mapper)
+                .onCloseSync(onClose) as unknown as AsyncIterable<number>;
+            const iterator = iterable[Symbol.asyncIterator]();
+
+            const error: Error = await iterator.next().then(
+                () => expect.fail('The iteration should have failed'),
+                (e) => e
+            );
+            expect(error.message).to.contain('failed');
+            expect(closed).to.equal(1);
+
+            expect(await iterator.next()).to.deep.equal({
+                done: true,
+                value: undefined,
+            });
+            await iterator.return!();
+            expect(closed).to.equal(1);
+        } finally {
+            onClose.reset();
+            mapper.reset();
+        }
+    });
+
     const streamAsStringRegex =
         /java\.util\.stream\.ReferencePipeline\$Head@[0-9a-z]+/;
 
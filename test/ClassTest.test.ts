@@ -1,4 +1,11 @@
-import { importClass, importClassAsync, appendClasspath } from '../.';
+import {
+    importClass,
+    importClassAsync,
+    appendClasspath,
+    getMethod,
+    types,
+    JavaError,
+} from '../.';
 import { expect } from 'chai';
 import { ClassTool, shouldIncreaseTimeout } from './testUtil';
 import path from 'path';
@@ -130,6 +137,204 @@ describe('ClassTest', () => {
             'ClassWithComplexProperties'
         );
 
+        classTool.createClass(
+            `
+        public class ClassWithOverloads {
+            public static String get(int i) {
+                return "int";
+            }
+            
+            public static String get(long l) {
+                return "long";
+            }
+            
+            public String call(double d, String s) {
+                return "double " + s;
+            }
+            
+            public String call(int i, String s) {
+                return "int " + s;
+            }
+            
+            public static String type(int i) {
+                return "int";
+            }
+            
+            public static String type(short s) {
+                return "short";
+            }
+            
+            public static String type(long l) {
+                return "long";
+            }
+            
+            public static String type(char c) {
+                return "char";
+            }
+            
+            public static String type(Object o) {
+                return o == null ? "null" : o.getClass().getName();
+            }
+            
+            public static String nullable(String s) {
+                return "string";
+            }
+            
+            public static String nullable(Integer i) {
+                return "integer";
+            }
+        }
+            `,
+            'ClassWithOverloads'
+        );
+
+        classTool.createClass(
+            `
+        import java.util.*;
+
+        public class ClassWithCollections {
+            public static String longs(List<Long> list) {
+                StringBuilder sb = new StringBuilder();
+                for (Long l : list) {
+                    sb.append(l.getClass().getSimpleName()).append(l);
+                }
+                return sb.toString();
+            }
+
+            public static String map(Map<String, List<Integer>> map) {
+                return map.getClass().getSimpleName() + map;
+            }
+
+            public static String set(Set<String> set) {
+                return set.getClass().getSimpleName() + set;
+            }
+
+            public static HashMap<String, List<Integer>> hashMap() {
+                HashMap<String, List<Integer>> map = new HashMap<>();
+                map.put("a", Arrays.asList(1, 2));
+                return map;
+            }
+
+            public static Map<String, Object> nested() {
+                Map<String, Object> map = new LinkedHashMap<>();
+                map.put("list", Arrays.asList(1, "a"));
+                map.put("set", new HashSet<>(Collections.singletonList(1.5)));
+                map.put("null", null);
+                return map;
+            }
+        }
+            `,
+            'ClassWithCollections'
+        );
+
+        classTool.createClass(
+            `
+        import java.util.Iterator;
+
+        public class SlowIterable implements Iterable<Integer> {
+            public Iterator<Integer> iterator() {
+                return new Iterator<Integer>() {
+                    private int i = 0;
+
+                    public boolean hasNext() {
+                        return true;
+                    }
+
+                    public Integer next() {
+                        try {
+                            Thread.sleep(20);
+                        } catch (InterruptedException ignored) {
+                        }
+                        return i++;
+                    }
+                };
+            }
+        }
+            `,
+            'SlowIterable'
+        );
+
+        classTool.createClass(
+            `
+        import java.util.concurrent.*;
+
+        public class ClassWithFutures {
+            public static CompletableFuture<String> upper(CompletionStage<String> stage) {
+                return stage.toCompletableFuture().thenApply(String::toUpperCase);
+            }
+
+            public static String get(Future<String> future) throws Exception {
+                return future.get();
+            }
+        }
+            `,
+            'ClassWithFutures'
+        );
+
+        classTool.createClass(
+            `
+        import java.util.*;
+
+        public class ClassWithOptionals {
+            public static String optional(Optional<Integer> optional) {
+                return optional.map(v -> v.getClass().getSimpleName() + v).orElse("empty");
+            }
+
+            public static String optionalLong(OptionalLong optional) {
+                return optional.toString();
+            }
+        }
+            `,
+            'ClassWithOptionals'
+        );
+
+        classTool.createClass(
+            `
+        import java.time.*;
+        import java.util.Date;
+
+        public class ClassWithDates {
+            public static long date(Date date) {
+                return date.getTime();
+            }
+
+            public static String instant(Instant instant) {
+                return instant.toString();
+            }
+
+            public static String local(LocalDateTime dateTime) {
+                return dateTime.toString();
+            }
+
+            public static String zoned(ZonedDateTime dateTime) {
+                return dateTime.toString();
+            }
+
+            public static long millis(long millis) {
+                return millis;
+            }
+        }
+            `,
+            'ClassWithDates'
+        );
+
+        classTool.createClass(
+            `
+        import java.math.*;
+
+        public class ClassWithBigNumbers {
+            public static BigInteger integer(BigInteger value) {
+                return value;
+            }
+
+            public static String decimal(BigDecimal value) {
+                return value.toString();
+            }
+        }
+            `,
+            'ClassWithBigNumbers'
+        );
+
         createJarWithBasicClass('test', 'ClassWithPackage', 'first.jar');
         createJarWithBasicClass(
             'test',
@@ -499,6 +704,193 @@ describe('ClassTest', () => {
         expect(instance.innerClass.s1).to.equal('abc');
     }).timeout(timeout);
 
+    it('Explicit overload selection', async () => {
+        const Class = importClass('ClassWithOverloads');
+
+        expect(Class.getSync(1)).to.equal('int');
+        expect(Class.getSync['(long)'](1)).to.equal('long');
+        expect(await Class.get['(long)'](1)).to.equal('long');
+        expect(getMethod(Class, 'get', ['long'])(1)).to.equal('long');
+        expect(getMethod(Class, 'get', ['J'])(1)).to.equal('long');
+
+        const instance = new Class();
+        expect(instance.callSync(1, 'a')).to.equal('int a');
+        expect(instance.callSync['(double,java.lang.String)'](1, 'a')).to.equal(
+            'double a'
+        );
+
+        const call = instance.call['(double,java.lang.String)'];
+        expect(await call(1, 'b')).to.equal('double b');
+
+        const bound = getMethod(
+            instance,
+            'call',
+            ['double', 'java.lang.String'],
+            true
+        );
+        expect(await bound(2, 'c')).to.equal('double c');
+        expect(() => getMethod(instance, 'call', ['short'])).to.throw();
+    }).timeout(timeout);
+
+    it('Type hints', () => {
+        const Class = importClass('ClassWithOverloads');
+
+        expect(Class.typeSync(types.short(1))).to.equal('short');
+        expect(Class.typeSync(types.long(1))).to.equal('long');
+        expect(Class.typeSync(types.long(1n))).to.equal('long');
+        expect(Class.typeSync(types.char('a'))).to.equal('char');
+        expect(Class.typeSync(types.float(1))).to.equal('java.lang.Float');
+        expect(Class.typeSync(types.byte(1))).to.equal('java.lang.Byte');
+        expect(Class.typeSync(types.boolean(true))).to.equal(
+            'java.lang.Boolean'
+        );
+
+        expect(Class.nullableSync(types.nullOf('java.lang.Integer'))).to.equal(
+            'integer'
+        );
+        expect(Class.nullableSync(types.nullOf('java.lang.String'))).to.equal(
+            'string'
+        );
+
+        const hint = types.short(5);
+        expect(hint.type).to.equal('short');
+        expect(hint.value).to.equal(5);
+
+        expect(() => types.char('ab')).to.throw();
+        expect(() => types.byte(300)).to.throw(
+            "Cannot convert 300 to 'byte'"
+        );
+        expect(() => types.short(-40000)).to.throw(
+            "Cannot convert -40000 to 'short'"
+        );
+        expect(() => types.int(3.7)).to.throw('the value is not an integer');
+        expect(() => types.long(2 ** 60)).to.throw('use a BigInt instead');
+        expect(() => types.long(2n ** 64n)).to.throw('out of range');
+    }).timeout(timeout);
+
+    it('Collections', () => {
+        const Class = importClass('ClassWithCollections');
+
+        expect(Class.longsSync([1, 2n])).to.equal('Long1Long2');
+        expect(Class.mapSync({ a: [1, 2] })).to.equal(
+            'LinkedHashMap{a=[1, 2]}'
+        );
+        expect(Class.mapSync(new Map([['b', [3]]]))).to.equal(
+            'LinkedHashMap{b=[3]}'
+        );
+        expect(Class.setSync(new Set(['a', 'b']))).to.equal(
+            'LinkedHashSet[a, b]'
+        );
+        expect(Class.setSync(['a', 'a'])).to.equal('LinkedHashSet[a]');
+        expect(Class.mapSync(Class.hashMapSync())).to.equal(
+            'HashMap{a=[1, 2]}'
+        );
+        expect(Class.nestedSync().sizeSync()).to.equal(3);
+
+        const Converting = importClass('ClassWithCollections', {
+            convertCollections: true,
+        });
+        expect(Converting.nestedSync()).to.deep.equal(
+            new Map<string, any>([
+                ['list', [1, 'a']],
+                ['set', new Set([1.5])],
+                ['null', null],
+            ])
+        );
+    }).timeout(timeout);
+
+    it('Break out of a slow iterator', async () => {
+        const SlowIterable = importClass('SlowIterable');
+        const iterable = new SlowIterable() as unknown as AsyncIterable<number>;
+        const iterator = iterable[Symbol.asyncIterator]();
+
+        // Fetching the first batch takes more than a second
+        const pending = iterator.next();
+        await new Promise((resolve) => setTimeout(resolve, 50));
+
+        const start = Date.now();
+        await iterator.return!();
+        expect(Date.now() - start).to.be.below(500);
+        expect(await pending).to.deep.equal({ done: true, value: undefined });
+    }).timeout(timeout);
+
+    it('Promises as futures', async () => {
+        const Class = importClass('ClassWithFutures', {
+            convertFutures: true,
+        });
+
+        expect(await Class.upperSync(Promise.resolve('test'))).to.equal(
+            'TEST'
+        );
+        expect(
+            await Class.get(
+                new Promise((resolve) => setTimeout(resolve, 10, 'test'))
+            )
+        ).to.equal('test');
+
+        const error: JavaError = await Class.upperSync(
+            Promise.reject(new Error('error'))
+        ).then(
+            () => expect.fail('The promise should have been rejected'),
+            (e: JavaError) => e
+        );
+        expect(error.cause.toString()).to.equal(
+            'io.github.markusjx.bridge.JavascriptException: error'
+        );
+    }).timeout(timeout);
+
+    it('Optional parameters', () => {
+        const Class = importClass('ClassWithOptionals');
+        const Optional = importClass('java.util.Optional');
+
+        expect(Class.optionalSync(5)).to.equal('Integer5');
+        expect(Class.optionalSync(null)).to.equal('empty');
+        expect(Class.optionalSync(Optional.ofSync(3))).to.equal('Integer3');
+        expect(Class.optionalLongSync(5n)).to.equal('OptionalLong[5]');
+        expect(Class.optionalLongSync(undefined)).to.equal(
+            'OptionalLong.empty'
+        );
+    }).timeout(timeout);
+
+    it('Date parameters', () => {
+        const Class = importClass('ClassWithDates', { timeZone: 'UTC' });
+        const date = new Date(Date.UTC(2020, 0, 2, 3, 4, 5));
+
+        expect(Class.dateSync(date)).to.equal(BigInt(date.getTime()));
+        expect(Class.instantSync(date)).to.equal('2020-01-02T03:04:05Z');
+        expect(Class.localSync(date)).to.equal('2020-01-02T03:04:05');
+        expect(Class.zonedSync(date)).to.equal('2020-01-02T03:04:05Z[UTC]');
+        expect(Class.millisSync(date)).to.equal(BigInt(date.getTime()));
+        expect(() => Class.dateSync(new Date(NaN))).to.throw(
+            'Cannot convert an invalid Date'
+        );
+
+        const TokyoClass = importClass('ClassWithDates', {
+            timeZone: 'Asia/Tokyo',
+        });
+        expect(TokyoClass.localSync(date)).to.equal('2020-01-02T12:04:05');
+    }).timeout(timeout);
+
+    it('Big number parameters', () => {
+        const Class = importClass('ClassWithBigNumbers', {
+            convertBigNumbers: true,
+        });
+        const big = 2n ** 100n + 1n;
+
+        expect(Class.integerSync(big)).to.equal(big);
+        expect(Class.integerSync(-big)).to.equal(-big);
+        expect(Class.integerSync(0n)).to.equal(0n);
+        expect(Class.integerSync(5)).to.equal(5n);
+        expect(() => Class.integerSync(1.5)).to.throw();
+
+        expect(Class.decimalSync('123.456')).to.equal('123.456');
+        expect(Class.decimalSync(0.1)).to.equal('0.1');
+        expect(Class.decimalSync(big)).to.equal(big.toString());
+        expect(Class.decimalSync({ unscaled: -123n, scale: 2 })).to.equal(
+            '-1.23'
+        );
+    }).timeout(timeout);
+
     after(function () {
         this.timeout(timeout);
         try {
@@ -226,4 +226,217 @@ describe('Config test', () => {
             );
         }
     });
+
+    it('long mode', () => {
+        const Long = importClass('java.lang.Long');
+        expect(Long.valueOfSync(5)).to.equal(5n);
+
+        const NumberLong = importClass('java.lang.Long', {
+            longMode: 'number',
+        });
+        expect(NumberLong.valueOfSync(5)).to.equal(5);
+        expect(NumberLong.parseLongSync('7')).to.equal(7);
+
+        config.longMode = 'auto';
+        expect(config.longMode).to.equal('auto');
+        clearClassProxies();
+
+        const AutoLong = importClass('java.lang.Long');
+        expect(AutoLong.valueOfSync(5)).to.equal(5);
+        expect(AutoLong.MAX_VALUE).to.equal(9223372036854775807n);
+
+        const LongStream = importClass('java.util.stream.LongStream');
+        expect(
+            LongStream.ofSync([1n, 9007199254740993n]).toArraySync()
+        ).to.deep.equal([1, 9007199254740993n]);
+
+        expect(() => (config.longMode = 'x' as any)).to.throw();
+    });
+
+    it('strict numbers', () => {
+        const Integer = importClass('java.lang.Integer');
+        expect(Integer.valueOfSync(3.7)).to.equal(3);
+
+        config.strictNumbers = true;
+        expect(config.strictNumbers).to.be.true;
+        clearClassProxies();
+
+        const StrictInteger = importClass('java.lang.Integer');
+        expect(StrictInteger.valueOfSync(-5)).to.equal(-5);
+        expect(() => StrictInteger.valueOfSync(3.7)).to.throw(
+            "Cannot convert 3.7 to 'int': the value is not an integer"
+        );
+        expect(() => StrictInteger.valueOfSync(2 ** 40)).to.throw(
+            "Cannot convert 1099511627776 to 'int': the value must be between -2147483648 and 2147483647"
+        );
+
+        const Long = importClass('java.lang.Long');
+        expect(Long.valueOfSync(42)).to.equal(42n);
+        expect(Long.valueOfSync(2n ** 53n + 2n)).to.equal(2n ** 53n + 2n);
+        expect(() => Long.valueOfSync(2 ** 53 + 2)).to.throw(
+            "Cannot convert 9007199254740994 to 'long': the value is not a safe integer, use a BigInt instead"
+        );
+        expect(() => Long.valueOfSync(2n ** 70n)).to.throw(
+            "Cannot convert BigInt to 'long': the value is out of range"
+        );
+
+        // Integers outside the int range are passed to objects as longs
+        const Objects = importClass('java.util.Objects');
+        expect(Objects.toStringSync(2 ** 40)).to.equal('1099511627776');
+        expect(Objects.requireNonNullSync(2 ** 40)).to.equal(2n ** 40n);
+        expect(Objects.requireNonNullSync(5)).to.equal(5);
+        expect(() => Objects.toStringSync(2 ** 53 + 2)).to.throw(
+            "Cannot convert 9007199254740994 to 'long': the value is not a safe integer, use a BigInt instead"
+        );
+
+        const LenientInteger = importClass('java.lang.Integer', {
+            strictNumbers: false,
+        });
+        expect(LenientInteger.valueOfSync(3.7)).to.equal(3);
+    });
+
+    it('convert futures', async () => {
+        const CompletableFuture = importClass(
+            'java.util.concurrent.CompletableFuture'
+        );
+        expect(CompletableFuture.completedFutureSync('test')).to.not.be.a(
+            'promise'
+        );
+
+        config.convertFutures = true;
+        expect(config.convertFutures).to.be.true;
+        clearClassProxies();
+
+        const ConvertingFuture = importClass(
+            'java.util.concurrent.CompletableFuture'
+        );
+        const result = ConvertingFuture.completedFutureSync('test');
+        expect(result).to.be.a('promise');
+        expect(await result).to.equal('test');
+        expect(await ConvertingFuture.completedFuture(5)).to.equal(5);
+    });
+
+    it('unwrap optionals', () => {
+        const Optional = importClass('java.util.Optional');
+        expect(Optional.ofSync('test').getSync()).to.equal('test');
+
+        config.unwrapOptionals = true;
+        expect(config.unwrapOptionals).to.be.true;
+        clearClassProxies();
+
+        const UnwrappingOptional = importClass('java.util.Optional');
+        expect(UnwrappingOptional.ofSync('test')).to.equal('test');
+        expect(UnwrappingOptional.emptySync()).to.be.null;
+
+        const OptionalInt = importClass('java.util.OptionalInt');
+        expect(OptionalInt.ofSync(5)).to.equal(5);
+        expect(OptionalInt.emptySync()).to.be.null;
+
+        const OptionalLong = importClass('java.util.OptionalLong');
+        expect(OptionalLong.ofSync(5)).to.equal(5n);
+    });
+
+    it('convert dates', () => {
+        const Instant = importClass('java.time.Instant');
+        expect(Instant.ofEpochMilliSync(1000).toEpochMilliSync()).to.equal(
+            1000n
+        );
+
+        config.convertDates = true;
+        expect(config.convertDates).to.be.true;
+        clearClassProxies();
+
+        const ConvertingInstant = importClass('java.time.Instant');
+        const date = ConvertingInstant.ofEpochMilliSync(1000);
+        expect(date).to.be.instanceOf(Date);
+        expect(date.getTime()).to.equal(1000);
+
+        const JavaDate = importClass('java.util.Date');
+        expect(new JavaDate(2000).toInstantSync()).to.deep.equal(
+            new Date(2000)
+        );
+    });
+
+    it('convert big numbers', () => {
+        const BigInteger = importClass('java.math.BigInteger');
+        expect(BigInteger.valueOfSync(5).toStringSync()).to.equal('5');
+
+        config.convertBigNumbers = true;
+        expect(config.convertBigNumbers).to.be.true;
+        clearClassProxies();
+
+        const ConvertingInteger = importClass('java.math.BigInteger');
+        expect(ConvertingInteger.valueOfSync(-5)).to.equal(-5n);
+
+        const BigDecimal = importClass('java.math.BigDecimal');
+        expect(BigDecimal.valueOfSync(5, 2)).to.equal('0.05');
+
+        config.bigDecimalMode = 'object';
+        expect(config.bigDecimalMode).to.equal('object');
+        clearClassProxies();
+
+        const ObjectDecimal = importClass('java.math.BigDecimal');
+        expect(ObjectDecimal.valueOfSync(-5, 2)).to.deep.equal({
+            unscaled: -5n,
+            scale: 2,
+        });
+    });
+
+    it('typed arrays', () => {
+        const Arrays = importClass('java.util.Arrays');
+        expect(Arrays.copyOfSync(new Int32Array([1, 2]), 3)).to.deep.equal([
+            1, 2, 0,
+        ]);
+
+        config.typedArrays = true;
+        expect(config.typedArrays).to.be.true;
+        clearClassProxies();
+
+        const TypedArrays = importClass('java.util.Arrays');
+        expect(TypedArrays.copyOfSync(new Int32Array([1, 2]), 3)).to.deep.equal(
+            new Int32Array([1, 2, 0])
+        );
+        expect(
+            TypedArrays.copyOfSync(new BigInt64Array([5n]), 1)
+        ).to.deep.equal(new BigInt64Array([5n]));
+        expect(TypedArrays.copyOfSync('ab', 2)).to.deep.equal(
+            new Uint16Array([97, 98])
+        );
+        expect(TypedArrays.copyOfSync([true], 1)).to.deep.equal([true]);
+    });
+
+    it('write back arrays', async () => {
+        const Arrays = importClass('java.util.Arrays');
+        const unchanged = [3, 1, 2];
+        Arrays.sortSync(unchanged);
+        expect(unchanged).to.deep.equal([3, 1, 2]);
+
+        config.writeBackArrays = true;
+        expect(config.writeBackArrays).to.be.true;
+        clearClassProxies();
+
+        const WriteBackArrays = importClass('java.util.Arrays');
+        const values = [3, 1, 2];
+        WriteBackArrays.sortSync(values);
+        expect(values).to.deep.equal([1, 2, 3]);
+
+        const shorts = new Int16Array([3, 2]);
+        await WriteBackArrays.sort(shorts);
+        expect(shorts).to.deep.equal(new Int16Array([2, 3]));
+    });
+
+    it('time zone', () => {
+        config.timeZone = 'Asia/Tokyo';
+        expect(config.timeZone).to.equal('Asia/Tokyo');
+        clearClassProxies();
+
+        const ZonedDateTime = importClass('java.time.ZonedDateTime');
+        const ZoneId = importClass('java.time.ZoneId');
+        const date = new Date(Date.UTC(2020, 0, 2, 3, 4, 5));
+
+        // The date is converted to a LocalDateTime in the configured zone
+        expect(
+            ZonedDateTime.ofSync(date, ZoneId.ofSync('UTC')).toStringSync()
+        ).to.equal('2020-01-02T12:04:05Z[UTC]');
+    });
 });
@@ -1,5 +1,5 @@
 import { expect } from 'chai';
-import { importClass } from '../.';
+import { futureToPromise, importClass, JavaError, out } from '../.';
 
 describe('Object test', () => {
     it('Create java.lang.Long from java.lang.String', () => {
@@ -18,4 +18,160 @@ describe('Object test', () => {
         System.out = null;
         expect(System.out).to.be.not.null;
     });
+
+    it('CompletableFuture to promise', async () => {
+        const CompletableFuture = importClass(
+            'java.util.concurrent.CompletableFuture'
+        );
+
+        const future = new CompletableFuture();
+        const promise = futureToPromise(future);
+        setTimeout(() => future.completeSync('test'), 10);
+        expect(await promise).to.equal('test');
+
+        const completed = CompletableFuture.completedFutureSync(5);
+        expect(await futureToPromise(completed)).to.equal(5);
+
+        const failed = new CompletableFuture();
+        const IllegalStateException = importClass(
+            'java.lang.IllegalStateException'
+        );
+        failed.completeExceptionallySync(new IllegalStateException('error'));
+
+        const error: JavaError = await futureToPromise(failed).then(
+            () => expect.fail('The promise should have been rejected'),
+            (e) => e
+        );
+        expect(error.message).to.contain(
+            'java.lang.IllegalStateException: error'
+        );
+        expect(error.cause.toString()).to.equal(
+            'java.lang.IllegalStateException: error'
+        );
+    });
+
+    it('Future to promise', async () => {
+        const Executors = importClass('java.util.concurrent.Executors');
+        const Thread = importClass('java.lang.Thread');
+        const executor = Executors.newSingleThreadExecutorSync();
+
+        try {
+            const future = executor.submitSync(
+                Executors.callableSync(new Thread(), 'test')
+            );
+            expect(await futureToPromise(future)).to.equal('test');
+        } finally {
+            executor.shutdownSync();
+        }
+    });
+
+    it('Cancel future using AbortSignal', async () => {
+        const CompletableFuture = importClass(
+            'java.util.concurrent.CompletableFuture'
+        );
+
+        const future = new CompletableFuture();
+        const controller = new AbortController();
+        const promise = futureToPromise(future, controller.signal);
+        controller.abort();
+
+        const error: JavaError = await promise.then(
+            () => expect.fail('The promise should have been rejected'),
+            (e) => e
+        );
+        expect(error.cause.toString()).to.equal(
+            'java.util.concurrent.CancellationException'
+        );
+        expect(future.isCancelledSync()).to.be.true;
+    });
+
+    it('Cancel a stage which does not support cancellation', async () => {
+        const CompletableFuture = importClass(
+            'java.util.concurrent.CompletableFuture'
+        );
+
+        const stage = new CompletableFuture().minimalCompletionStageSync();
+        const controller = new AbortController();
+        const promise = futureToPromise(stage, controller.signal);
+        controller.abort();
+
+        await promise.then(
+            () => expect.fail('The promise should have been rejected'),
+            (e) => expect(e.message).to.equal('The java future was cancelled')
+        );
+    });
+
+    it('Reject a stage collected before completing', async () => {
+        const CompletableFuture = importClass(
+            'java.util.concurrent.CompletableFuture'
+        );
+        const System = importClass('java.lang.System');
+
+        let error: Error | null = null;
+        futureToPromise(new CompletableFuture()).then(
+            () => expect.fail('The promise should have been rejected'),
+            (e) => (error = e)
+        );
+
+        for (let i = 0; i < 100 && error === null; i++) {
+            global.gc!();
+            System.gcSync();
+            await new Promise((resolve) => setTimeout(resolve, 50));
+        }
+
+        expect(error!.message).to.equal(
+            'The java future was garbage collected without being completed'
+        );
+    }).timeout(10e3);
+
+    it('Typed arrays as primitive arrays', () => {
+        const Arrays = importClass('java.util.Arrays');
+
+        expect(Arrays.toStringSync(new Int32Array([1, 2]))).to.equal('[1, 2]');
+        expect(Arrays.toStringSync(new Float64Array([1.5]))).to.equal('[1.5]');
+        expect(Arrays.toStringSync(new Float32Array([2.5]))).to.equal('[2.5]');
+        expect(Arrays.toStringSync(new Int16Array([-3]))).to.equal('[-3]');
+        expect(Arrays.toStringSync(new BigInt64Array([2n ** 62n]))).to.equal(
+            '[4611686018427387904]'
+        );
+        expect(Arrays.toStringSync(new Uint16Array([65, 66]))).to.equal(
+            '[A, B]'
+        );
+        expect(Arrays.toStringSync(new Uint8Array([255]))).to.equal('[-1]');
+        expect(Arrays.toStringSync(new Int32Array(0))).to.equal('[]');
+        expect(() => Arrays.hashCodeSync(new Uint32Array([1]))).to.throw();
+
+        const JavaString = importClass('java.lang.String');
+        expect(JavaString.copyValueOfSync('text')).to.equal('text');
+    });
+
+    it('Out arguments', async () => {
+        const Arrays = importClass('java.util.Arrays');
+
+        const ints = new Int32Array([3, 1, 2]);
+        Arrays.sortSync(out(ints));
+        expect(ints).to.deep.equal(new Int32Array([1, 2, 3]));
+
+        const strings = ['c', 'a', 'b'];
+        Arrays.sortSync(out(strings));
+        expect(strings).to.deep.equal(['a', 'b', 'c']);
+
+        const doubles = new Float64Array([2.5, 1.5]);
+        await Arrays.sort(out(doubles));
+        expect(doubles).to.deep.equal(new Float64Array([1.5, 2.5]));
+
+        const unchanged = new Int32Array([2, 1]);
+        Arrays.sortSync(unchanged);
+        expect(unchanged).to.deep.equal(new Int32Array([2, 1]));
+
+        const ByteArrayInputStream = importClass(
+            'java.io.ByteArrayInputStream'
+        );
+        const buffer = Buffer.alloc(3);
+        const stream = new ByteArrayInputStream(Buffer.from([250, 2, 3]));
+        expect(stream.readSync(out(buffer))).to.equal(3);
+        expect(buffer).to.deep.equal(Buffer.from([250, 2, 3]));
+
+        expect(() => out(1 as any)).to.throw();
+    });
 });
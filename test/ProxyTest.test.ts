@@ -237,6 +237,134 @@ describe('ProxyTest', () => {
         });
     });
 
+    describe('Re-entrant proxy calls', () => {
+        before(() => {
+            java.clearClassProxies();
+        });
+
+        it('Call a proxy from a sync call', () => {
+            const values: number[] = [];
+            const proxy = java.newProxy('java.util.function.Consumer', {
+                accept: (value: number) => {
+                    values.push(value);
+                },
+            });
+
+            const ArrayList = java.importClass('java.util.ArrayList');
+            const list = new ArrayList();
+            list.addSync(1);
+            list.addSync(2);
+            list.forEachSync(proxy);
+            proxy.reset();
+
+            expect(values).to.have.ordered.members([1, 2]);
+        });
+
+        it('Nested sync calls', () => {
+            const values: number[] = [];
+            const ArrayList = java.importClass('java.util.ArrayList');
+            const list = new ArrayList();
+            list.addSync(1);
+            list.addSync(2);
+
+            list.forEachSync((value: number) => {
+                const inner = new ArrayList();
+                inner.addSync(value * 2);
+                inner.forEachSync((innerValue: number) =>
+                    values.push(innerValue)
+                );
+            });
+
+            expect(values).to.have.ordered.members([2, 4]);
+        });
+
+        it('Throw an error in a proxy called from a sync call', () => {
+            const proxy = java.newProxy('java.util.function.Consumer', {
+                accept: () => {
+                    throw new Error('Error');
+                },
+            });
+
+            const ArrayList = java.importClass('java.util.ArrayList');
+            const list = new ArrayList();
+            list.addSync(1);
+
+            expect(() => list.forEachSync(proxy)).to.throw(
+                'io.github.markusjx.bridge.JavascriptException: Error'
+            );
+            proxy.reset();
+        });
+
+        it('Return a promise from a proxy called from a sync call', () => {
+            const ArrayList = java.importClass('java.util.ArrayList');
+            const list = new ArrayList();
+            list.addSync(1);
+
+            expect(() => list.forEachSync(async () => {})).to.throw(
+                'did not return a value synchronously'
+            );
+        });
+
+        it('Call a proxy from another java thread during a sync call', () => {
+            java.config.runEventLoopWhenInterfaceProxyIsActive = true;
+
+            let called = false;
+            const proxy = java.newProxy('java.lang.Runnable', {
+                run: () => {
+                    called = true;
+                },
+            });
+
+            try {
+                const Thread = java.importClass('java.lang.Thread');
+                const thread = new Thread(proxy);
+                thread.startSync();
+                thread.joinSync();
+            } finally {
+                proxy.reset();
+                java.config.runEventLoopWhenInterfaceProxyIsActive = false;
+            }
+
+            expect(called).to.be.true;
+        });
+
+        it('Return a promise to another java thread', async () => {
+            java.config.runEventLoopWhenInterfaceProxyIsActive = true;
+
+            const Executors = java.importClass(
+                'java.util.concurrent.Executors'
+            );
+            const TimeUnit = java.importClass('java.util.concurrent.TimeUnit');
+            const Thread = java.importClass('java.lang.Thread');
+            const executor = Executors.newSingleThreadScheduledExecutorSync();
+            const proxy = java.newProxy('java.util.concurrent.Callable', {
+                call: async () => {
+                    await new Promise((resolve) => setTimeout(resolve, 10));
+                    return 'value';
+                },
+            });
+
+            try {
+                const future = executor.scheduleSync(
+                    proxy,
+                    100,
+                    TimeUnit.MILLISECONDS
+                );
+                Thread.sleepSync(400);
+                expect(await future.get()).to.equal('value');
+            } finally {
+                executor.shutdownSync();
+                proxy.reset();
+                java.config.runEventLoopWhenInterfaceProxyIsActive = false;
+            }
+        });
+
+        after(function () {
+            this.timeout(timeoutMs);
+            global.gc!();
+        });
+    });
+
     describe('Multiple proxies', () => {
         const proxies: JavaInterfaceProxy[] = [];
 
@@ -283,6 +411,550 @@ describe('ProxyTest', () => {
         });
     });
 
+    describe('Multiple interfaces', () => {
+        it('Implement multiple interfaces', async () => {
+            const calls: string[] = [];
+            const proxy = java.newProxy(
+                ['java.lang.Runnable', 'java.lang.AutoCloseable'],
+                {
+                    run: () => calls.push('run'),
+                    close: () => calls.push('close'),
+                }
+            );
+
+            const Thread = java.importClass<typeof JThread>('java.lang.Thread');
+            const thread = new Thread(proxy);
+            await thread.start();
+            await thread.join();
+
+            const AutoCloseable = java.importClass('java.lang.AutoCloseable');
+            const close = AutoCloseable.class.getMethodSync('close', []);
+            await close.invoke(proxy, []);
+            proxy.reset();
+
+            expect(calls).to.have.ordered.members(['run', 'close']);
+        });
+
+        it('Implement methods of a specific interface', async () => {
+            const proxy = java.newProxy(
+                [
+                    'java.util.function.IntSupplier',
+                    'java.util.function.LongSupplier',
+                ],
+                {
+                    'java.util.function.IntSupplier.getAsInt': () => 1,
+                    getAsLong: () => 2n,
+                }
+            );
+
+            const IntSupplier = java.importClass(
+                'java.util.function.IntSupplier'
+            );
+            const LongSupplier = java.importClass(
+                'java.util.function.LongSupplier'
+            );
+
+            const getAsInt = IntSupplier.class.getMethodSync('getAsInt', []);
+            const getAsLong = LongSupplier.class.getMethodSync('getAsLong', []);
+            expect(await getAsInt.invoke(proxy, [])).to.equal(1);
+            expect(await getAsLong.invoke(proxy, [])).to.equal(2n);
+            proxy.reset();
+        });
+
+        it('Implement no interface', () => {
+            expect(() => java.newProxy([], {})).to.throw(
+                'At least one interface must be implemented'
+            );
+        });
+
+        after(function () {
+            this.timeout(timeoutMs);
+            global.gc!();
+        });
+    });
+
+    describe('Object methods', () => {
+        const createProxy = (name: string) =>
+            java.newProxy('java.lang.Runnable', {
+                run: () => {},
+                equals: (other: JavaClass | null) =>
+                    other?.toStringSync() === name,
+                hashCode: () => 42,
+                toString: () => name,
+            });
+
+        it('Override equals, hashCode and toString', () => {
+            const proxies = [
+                createProxy('a'),
+                createProxy('a'),
+                createProxy('b'),
+            ];
+            const HashSet = java.importClass('java.util.HashSet');
+            const set = new HashSet();
+            proxies.forEach((proxy) => set.addSync(proxy));
+
+            expect(set.sizeSync()).to.equal(2);
+            expect(set.toStringSync()).to.equal('[a, b]');
+            proxies.forEach((proxy) => proxy.reset());
+        });
+
+        it('Use the default equals and hashCode', () => {
+            const proxy = java.newProxy('java.lang.Runnable', {
+                run: () => {},
+            });
+            const HashSet = java.importClass('java.util.HashSet');
+            const set = new HashSet();
+            set.addSync(proxy);
+            set.addSync(proxy);
+
+            expect(set.sizeSync()).to.equal(1);
+            expect(set.containsSync(proxy)).to.be.true;
+            proxy.reset();
+        });
+
+        after(function () {
+            this.timeout(timeoutMs);
+            global.gc!();
+        });
+    });
+
+    describe('Function arguments', () => {
+        it('Pass a function as a functional interface', async () => {
+            const ArrayList = java.importClass('java.util.ArrayList');
+            const list = new ArrayList();
+            list.addSync('a');
+            list.addSync('b');
+
+            const values: string[] = [];
+            await list.forEach((value: string) => values.push(value));
+            expect(values).to.have.members(['a', 'b']);
+        });
+
+        it('Return a value from a function', async () => {
+            const CompletableFuture = java.importClass(
+                'java.util.concurrent.CompletableFuture'
+            );
+            const future = CompletableFuture.supplyAsyncSync(() => 'supplied');
+            expect(await future.get()).to.equal('supplied');
+        });
+
+        it('Return a promise from a function', async () => {
+            const CompletableFuture = java.importClass(
+                'java.util.concurrent.CompletableFuture'
+            );
+            const future = CompletableFuture.supplyAsyncSync(async () => 42);
+            expect(await future.get()).to.equal(42);
+        });
+
+        it('Throw an error in a function', async () => {
+            const ArrayList = java.importClass('java.util.ArrayList');
+            const list = new ArrayList();
+            list.addSync('a');
+
+            await expect(
+                list.forEach(() => {
+                    throw new Error('Error in function');
+                })
+            ).to.be.rejectedWith('Error in function');
+        });
+
+        it('Call a function after the call returned', async () => {
+            const Thread = java.importClass('java.lang.Thread');
+
+            let called = false;
+            const thread = new Thread(() => {
+                called = true;
+            });
+            global.gc!();
+            await thread.start();
+            await thread.join();
+            expect(called).to.be.true;
+        });
+
+        it('Release functions after the call', async function () {
+            this.timeout(timeoutMs);
+            const ArrayList = java.importClass('java.util.ArrayList');
+            const System = java.importClass('java.lang.System');
+            const list = new ArrayList();
+            list.addSync(1);
+
+            const idsBefore = java.proxies.list().map((info) => info.id);
+            const created = () =>
+                java.proxies
+                    .list()
+                    .filter((info) => !idsBefore.includes(info.id));
+
+            let sum = 0;
+            for (let i = 0; i < 20; i++) {
+                await list.forEach((value: number) => {
+                    sum += value;
+                });
+            }
+            expect(sum).to.equal(20);
+
+            // The proxies are removed once java collected them
+            for (let i = 0; i < 50 && created().length > 0; i++) {
+                System.gcSync();
+                await new Promise((resolve) => setTimeout(resolve, 20));
+            }
+            expect(created()).to.be.empty;
+        });
+
+        it('Pass a function to a sync method', () => {
+            java.clearClassProxies();
+            java.config.runEventLoopWhenInterfaceProxyIsActive = true;
+            const ArrayList = java.importClass('java.util.ArrayList');
+            const list = new ArrayList();
+            list.addSync(1);
+            list.addSync(2);
+
+            expect(list.removeIfSync((value: number) => value === 1)).to.be
+                .true;
+            expect(list.toStringSync()).to.equal('[2]');
+        });
+
+        after(function () {
+            this.timeout(timeoutMs);
+            java.config.runEventLoopWhenInterfaceProxyIsActive = false;
+            global.gc!();
+        });
+    });
+
+    describe('Declared method types', () => {
+        it('Return an int', async () => {
+            const ArrayList = java.importClass('java.util.ArrayList');
+            const list = new ArrayList();
+            list.addSync(3);
+            list.addSync(1);
+            list.addSync(2);
+
+            const comparator = java.newProxy('java.util.Comparator', {
+                compare: (a: number, b: number): number => (a - b) * 5,
+            });
+
+            await list.sort(comparator);
+            comparator.reset();
+            expect(list.toStringSync()).to.equal('[1, 2, 3]');
+        });
+
+        it('Return a double', async () => {
+            const DoubleStream = java.importClass(
+                'java.util.stream.DoubleStream'
+            );
+            const stream = DoubleStream.ofSync([1.5, 2.5]).mapSync(() => 3);
+            expect(await stream.sum()).to.equal(6);
+        });
+
+        it('Pass primitive arguments', async () => {
+            const IntStream = java.importClass('java.util.stream.IntStream');
+            const args: unknown[] = [];
+            const stream = IntStream.ofSync([1, 2]).mapSync((value: number) => {
+                args.push(value);
+                return value * 2;
+            });
+
+            expect(await stream.sum()).to.equal(6);
+            expect(args).to.have.members([1, 2]);
+        });
+
+        it('Return nothing from a method returning a primitive', async () => {
+            const IntStream = java.importClass('java.util.stream.IntStream');
+            const stream = IntStream.ofSync([1]).mapSync(() => {});
+
+            await expect(stream.sum()).to.be.rejectedWith(
+                'Expected the proxy method to return a value of type int'
+            );
+        });
+
+        after(function () {
+            this.timeout(timeoutMs);
+            global.gc!();
+        });
+    });
+
+    describe('Async methods', () => {
+        it('Await a promise', async () => {
+            const proxy = java.newProxy('java.util.concurrent.Callable', {
+                call: async (): Promise<string> => {
+                    await new Promise((resolve) => setTimeout(resolve, 50));
+                    return 'value';
+                },
+            });
+            const FutureTask = java.importClass(
+                'java.util.concurrent.FutureTask'
+            );
+            const task = new FutureTask(proxy);
+
+            await task.run();
+            expect(await task.get()).to.equal('value');
+            proxy.reset();
+        });
+
+        it('Throw if a promise is rejected', async () => {
+            const proxy = java.newProxy('java.util.concurrent.Callable', {
+                call: async (): Promise<string> => {
+                    throw new Error('Rejected');
+                },
+            });
+            const FutureTask = java.importClass(
+                'java.util.concurrent.FutureTask'
+            );
+            const task = new FutureTask(proxy);
+
+            await task.run();
+            await expect(task.get()).to.be.rejectedWith('Rejected');
+            proxy.reset();
+        });
+
+        it('Throw if a non-error value is thrown', async () => {
+            const proxy = java.newProxy('java.util.concurrent.Callable', {
+                call: (): string => {
+                    throw 'Thrown';
+                },
+            });
+            const FutureTask = java.importClass(
+                'java.util.concurrent.FutureTask'
+            );
+            const task = new FutureTask(proxy);
+
+            await task.run();
+            await expect(task.get()).to.be.rejectedWith('Thrown');
+            proxy.reset();
+        });
+
+        it('Pass results to the callback of native proxies', async () => {
+            const proxy = java
+                .getJavaInstance()!
+                .createInterfaceProxy('java.util.concurrent.Callable', {
+                    call: (
+                        err: Error | null,
+                        callback: (err: Error | null, data?: any) => void
+                    ) => {
+                        setTimeout(() => callback(null, 'value'), 10);
+                    },
+                });
+            const FutureTask = java.importClass(
+                'java.util.concurrent.FutureTask'
+            );
+            const task = new FutureTask(proxy);
+
+            await task.run();
+            expect(await task.get()).to.equal('value');
+            proxy.reset();
+        });
+    });
+
+    describe('Non-blocking void methods', () => {
+        it('Return before the method was called', async () => {
+            let called = false;
+            const proxy = java.newProxy(
+                'java.lang.Runnable',
+                {
+                    run: (): void => {
+                        called = true;
+                    },
+                },
+                { nonBlockingVoid: true }
+            );
+            const Thread = java.importClass('java.lang.Thread');
+            const thread = new Thread(proxy);
+
+            // The event loop is blocked, so the method can't have been called
+            thread.startSync();
+            thread.joinSync();
+            expect(called).to.be.false;
+
+            await new Promise((resolve) => setTimeout(resolve, 100));
+            expect(called).to.be.true;
+            proxy.reset();
+        });
+
+        it('Pass errors to onError', async () => {
+            const errors: [Error, string][] = [];
+            const proxy = java.newProxy(
+                'java.lang.Runnable',
+                {
+                    run: async (): Promise<void> => {
+                        throw new Error('Failed');
+                    },
+                },
+                {
+                    nonBlockingVoid: ['run'],
+                    onError: (error: Error, method: string): void => {
+                        errors.push([error, method]);
+                    },
+                }
+            );
+            const Thread = java.importClass('java.lang.Thread');
+            const thread = new Thread(proxy);
+
+            await thread.start();
+            await thread.join();
+            await new Promise((resolve) => setTimeout(resolve, 100));
+            expect(errors).to.have.lengthOf(1);
+            expect(errors[0][0]).to.be.instanceOf(Error);
+            expect(errors[0][0].message).to.equal('Failed');
+            expect(errors[0][1]).to.equal('run');
+            proxy.reset();
+        });
+
+        it('Reject unknown method names', () => {
+            expect(() =>
+                java.newProxy(
+                    'java.lang.Runnable',
+                    { run: (): void => {} },
+                    { nonBlockingVoid: ['stop'] }
+                )
+            ).to.throw("The non-blocking method 'stop' is not implemented");
+        });
+    });
+
+    describe('Timeouts', () => {
+        it('Throw a TimeoutException', async () => {
+            const proxy = java.newProxy(
+                'java.util.concurrent.Callable',
+                { call: () => new Promise(() => {}) },
+                { timeoutMs: 100 }
+            );
+            const FutureTask = java.importClass(
+                'java.util.concurrent.FutureTask'
+            );
+            const task = new FutureTask(proxy);
+
+            await task.run();
+            await expect(task.get()).to.be.rejectedWith(
+                'java.util.concurrent.TimeoutException'
+            );
+            proxy.reset();
+        });
+
+        it('Return before the timeout', async () => {
+            const proxy = java.newProxy(
+                'java.util.concurrent.Callable',
+                { call: async () => 'value' },
+                { timeoutMs: timeoutMs }
+            );
+            const FutureTask = java.importClass(
+                'java.util.concurrent.FutureTask'
+            );
+            const task = new FutureTask(proxy);
+
+            await task.run();
+            expect(await task.get()).to.equal('value');
+            proxy.reset();
+        });
+
+        after(function () {
+            this.timeout(timeoutMs);
+            global.gc!();
+        });
+    });
+
+    describe('Garbage collection', () => {
+        it('Call a proxy collected by javascript', async () => {
+            const ArrayList = java.importClass('java.util.ArrayList');
+            const list = new ArrayList();
+
+            let calls = 0;
+            (() => {
+                list.addSync(
+                    java.newProxy('java.lang.Runnable', {
+                        run: () => {
+                            calls++;
+                        },
+                    })
+                );
+            })();
+            global.gc!();
+
+            await list.getSync(0).run();
+            expect(calls).to.equal(1);
+            list.clearSync();
+        });
+
+        after(function () {
+            this.timeout(timeoutMs);
+            global.gc!();
+        });
+    });
+
+    describe('Introspection', () => {
+        const findProxy = (id: number) =>
+            java.proxies.list().find((info) => info.id === id);
+
+        it('List proxies', async () => {
+            const idsBefore = java.proxies.list().map((info) => info.id);
+            const proxy = java.newProxy(
+                ['java.lang.Runnable', 'java.lang.AutoCloseable'],
+                {
+                    run: () => {},
+                    close: () => {},
+                }
+            );
+            const Thread = java.importClass('java.lang.Thread');
+            const thread = new Thread(proxy);
+            await thread.start();
+            await thread.join();
+
+            const created = java.proxies
+                .list()
+                .filter((info) => !idsBefore.includes(info.id));
+            expect(created).to.have.lengthOf(1);
+            expect(created[0].interfaces).to.deep.equal([
+                'java.lang.Runnable',
+                'java.lang.AutoCloseable',
+            ]);
+            expect(created[0].methods).to.deep.equal(['close', 'run']);
+            expect(created[0].calls).to.deep.equal({ close: 0, run: 1 });
+            expect(created[0].keepAsDaemon).to.be.false;
+            expect(created[0].daemon).to.be.false;
+            expect(created[0].creationStack).to.be.undefined;
+
+            proxy.reset();
+            expect(findProxy(created[0].id)).to.be.undefined;
+        });
+
+        it('Capture the creation stack', () => {
+            java.config.captureProxyStacks = true;
+            const idsBefore = java.proxies.list().map((info) => info.id);
+            const proxy = java.newProxy('java.lang.Runnable', {
+                run: () => {},
+            });
+            java.config.captureProxyStacks = false;
+
+            const created = java.proxies
+                .list()
+                .find((info) => !idsBefore.includes(info.id));
+            expect(created!.creationStack).to.include('ProxyTest');
+            proxy.reset();
+        });
+
+        it('Destroy a proxy by id', () => {
+            const idsBefore = java.proxies.list().map((info) => info.id);
+            const proxy = java.newProxy('java.lang.Runnable', {
+                run: () => {},
+            });
+            const id = java.proxies
+                .list()
+                .find((info) => !idsBefore.includes(info.id))!.id;
+
+            java.proxies.destroy(id);
+            expect(findProxy(id)).to.be.undefined;
+            expect(() => java.proxies.destroy(id)).to.throw(
+                `No proxy with the id ${id} exists`
+            );
+            expect(() => proxy.reset()).to.throw(
+                'This instance is already destroyed'
+            );
+        });
+
+        after(function () {
+            this.timeout(timeoutMs);
+            java.config.captureProxyStacks = false;
+            global.gc!();
+        });
+    });
+
     describe('Daemon proxies', () => {
         const shouldSkip = semver.lt(javaVersion, '12.0.0');
 
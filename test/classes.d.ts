@@ -57,6 +57,10 @@ export declare class StreamClass<T extends JavaType> extends JavaClass {
     toListSync(): ListClass<T>;
 
     toList(): Promise<ListClass<T>>;
+
+    mapSync(mapper: JavaInterfaceProxy): StreamClass<T>;
+
+    onCloseSync(closeHandler: JavaInterfaceProxy): StreamClass<T>;
 }
 
 export declare class ListClass<T extends JavaType> extends JavaClass {
@@ -97,6 +101,8 @@ export declare class ListClass<T extends JavaType> extends JavaClass {
     streamSync(): StreamClass<T>;
 
     stream(): Promise<StreamClass<T>>;
+
+    iteratorSync(): JavaClass;
 }
 
 export declare class ArrayListClass<T extends JavaType> extends ListClass<T> {
@@ -28,5 +28,10 @@ import * as java from './java';
 
 export default java;
 export { getJavaLibPath, InterfaceProxyOptions } from '../native';
+export type {
+    JavaTypeHint,
+    JavaOutArgument,
+    InterfaceProxyInfo,
+} from '../native';
 export { getJavaVersion, getJavaVersionSync } from './util';
 export type { JavaConfig } from '../native';
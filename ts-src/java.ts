@@ -4,6 +4,8 @@ import {
     JavaOptions,
     JavaConfig,
     ClassConfiguration,
+    JavaTypeHint,
+    JavaOutArgument,
 } from '../native';
 import {
     JavaClass,
@@ -14,7 +16,12 @@ import {
 } from './definitions';
 import { getJavaLibPath, getNativeLibPath } from './nativeLib';
 
-export { clearDaemonProxies, clearClassProxies, logging } from '../native';
+export {
+    clearDaemonProxies,
+    clearClassProxies,
+    logging,
+    proxies,
+} from '../native';
 
 /**
  * The static java instance
@@ -371,6 +378,251 @@ export function isInstanceOf<T extends object>(
     return javaInstance!.isInstanceOf(this_obj, other);
 }
 
+/**
+ * Get a specific overload of a java method by its parameter types.
+ * Use this if the automatic overload resolution picks the wrong
+ * method or the call is ambiguous. The returned function calls
+ * the selected method directly, without trying to match the
+ * passed arguments against other overloads.
+ *
+ * If `obj` is a class, the static method will be returned.
+ * Otherwise, the returned function is bound to `obj`.
+ *
+ * Overloaded methods can also be selected using their parameter
+ * signature as a property of the method, e.g.
+ * `obj.method['(int,java.lang.String)']`.
+ *
+ * ## Example
+ * ```ts
+ * import { getMethod, importClass } from 'java-bridge';
+ *
+ * const JString = importClass('java.lang.String');
+ * const valueOf = getMethod(JString, 'valueOf', ['int']);
+ * valueOf(5); // '5'
+ *
+ * const StringBuilder = importClass('java.lang.StringBuilder');
+ * const builder = new StringBuilder();
+ *
+ * const append = getMethod(builder, 'append', ['double'], true);
+ * await append(1); // builder.toStringSync() === '1.0'
+ *
+ * // This is the same as
+ * builder.appendSync['(double)'](1);
+ * ```
+ *
+ * @param obj the java object or class to get the method from
+ * @param name the name of the method
+ * @param parameterTypes the java types of the method parameters
+ * @param async whether the returned function should return a promise
+ * @return the selected method
+ * @since 2.9.0
+ */
+export function getMethod(
+    obj: JavaClass | UnknownJavaClassType,
+    name: string,
+    parameterTypes: string[],
+    async: boolean = false
+): (...args: any[]) => any {
+    ensureJvm();
+    return javaInstance!.getMethod(obj, name, parameterTypes, async);
+}
+
+/**
+ * Convert a `java.util.concurrent.CompletionStage` or
+ * `java.util.concurrent.Future` to a promise.
+ * Completion stages resolve the promise once they complete,
+ * other futures are waited for on a background thread.
+ * If the future completes exceptionally, the promise is
+ * rejected with an error whose `cause` is the java exception.
+ * If `signal` is aborted, the future is cancelled, rejecting
+ * the promise with a `java.util.concurrent.CancellationException`.
+ * Completion stages which can't be cancelled or which are garbage
+ * collected without being completed reject the promise as well.
+ *
+ * Set {@link JavaConfig.convertFutures} in order to convert
+ * all futures returned from java to promises automatically.
+ *
+ * ## Example
+ * ```ts
+ * import { futureToPromise, importClass } from 'java-bridge';
+ *
+ * const CompletableFuture = importClass(
+ *     'java.util.concurrent.CompletableFuture'
+ * );
+ * const future = new CompletableFuture();
+ *
+ * const controller = new AbortController();
+ * const promise = futureToPromise(future, controller.signal);
+ *
+ * future.completeSync('test');
+ * await promise; // 'test'
+ * ```
+ *
+ * @param future the future to convert
+ * @param signal an optional signal used to cancel the future
+ * @return a promise resolving to the result of the future
+ * @since 2.9.0
+ */
+export function futureToPromise<T = any>(
+    future: JavaClass,
+    signal?: AbortSignal
+): Promise<T> {
+    ensureJvm();
+    return javaInstance!.futureToPromise(future, signal);
+}
+
+/**
+ * Mark an array as an out argument.
+ * Java arrays are copies of the javascript arrays passed to a method,
+ * so changes made by the method are usually not visible in javascript.
+ * The contents of arrays wrapped using this method are copied back
+ * into the original array after the method returns, even if it throws.
+ * Buffers, typed arrays and plain arrays are supported.
+ * Set {@link JavaConfig.writeBackArrays} to copy back all arrays.
+ *
+ * ## Example
+ * ```ts
+ * import { importClass, out } from 'java-bridge';
+ *
+ * const Arrays = importClass('java.util.Arrays');
+ * const values = new Int32Array([3, 1, 2]);
+ *
+ * Arrays.sortSync(out(values));
+ * console.log(values); // Int32Array(3) [ 1, 2, 3 ]
+ * ```
+ *
+ * @param value the array to wrap
+ * @return the wrapped array
+ * @since 2.9.0
+ */
+export function out<T extends unknown[] | ArrayBufferView>(
+    value: T
+): JavaOutArgument {
+    ensureJvm();
+    return javaInstance!.createOutArgument(value);
+}
+
+/**
+ * Create values with an explicit java type.
+ * Javascript numbers may be converted to a number of java types,
+ * which may cause the wrong overload of a method to be selected.
+ * Values wrapped using these methods are treated as an exact match
+ * for their type when selecting an overload and are boxed to their
+ * type when passed to a method accepting objects.
+ *
+ * ## Example
+ * ```ts
+ * import { types, importClass } from 'java-bridge';
+ *
+ * const ArrayList = importClass('java.util.ArrayList');
+ * const list = new ArrayList();
+ *
+ * // Add a java.lang.Short instead of a java.lang.Integer
+ * list.addSync(types.short(3));
+ *
+ * const JString = importClass('java.lang.String');
+ * // Calls String.valueOf(char) instead of String.valueOf(Object)
+ * JString.valueOfSync(types.char('a'));
+ *
+ * // Pass null as a java.lang.String, selecting
+ * // someMethod(String) instead of someMethod(Integer)
+ * instance.someMethodSync(types.nullOf('java.lang.String'));
+ * ```
+ *
+ * @since 2.9.0
+ */
+export namespace types {
+    /**
+     * Create a java `byte`.
+     *
+     * @param value the value to convert
+     */
+    export function byte(value: number): JavaTypeHint {
+        ensureJvm();
+        return javaInstance!.createTypeHint('byte', value);
+    }
+
+    /**
+     * Create a java `short`.
+     *
+     * @param value the value to convert
+     */
+    export function short(value: number): JavaTypeHint {
+        ensureJvm();
+        return javaInstance!.createTypeHint('short', value);
+    }
+
+    /**
+     * Create a java `int`.
+     *
+     * @param value the value to convert
+     */
+    export function int(value: number): JavaTypeHint {
+        ensureJvm();
+        return javaInstance!.createTypeHint('int', value);
+    }
+
+    /**
+     * Create a java `long`.
+     *
+     * @param value the value to convert
+     */
+    export function long(value: number | bigint): JavaTypeHint {
+        ensureJvm();
+        return javaInstance!.createTypeHint('long', value);
+    }
+
+    /**
+     * Create a java `float`.
+     *
+     * @param value the value to convert
+     */
+    export function float(value: number): JavaTypeHint {
+        ensureJvm();
+        return javaInstance!.createTypeHint('float', value);
+    }
+
+    /**
+     * Create a java `double`.
+     *
+     * @param value the value to convert
+     */
+    export function double(value: number): JavaTypeHint {
+        ensureJvm();
+        return javaInstance!.createTypeHint('double', value);
+    }
+
+    /**
+     * Create a java `char`.
+     *
+     * @param value a string containing exactly one character
+     */
+    export function char(value: string): JavaTypeHint {
+        ensureJvm();
+        return javaInstance!.createTypeHint('char', value);
+    }
+
+    /**
+     * Create a java `boolean`.
+     *
+     * @param value the value to convert
+     */
+    export function boolean(value: boolean): JavaTypeHint {
+        ensureJvm();
+        return javaInstance!.createTypeHint('boolean', value);
+    }
+
+    /**
+     * Create a `null` value of a specific java type.
+     *
+     * @param className the name of the class, e.g. `java.lang.String`
+     */
+    export function nullOf(className: string): JavaTypeHint {
+        ensureJvm();
+        return javaInstance!.createTypeHint(className, null);
+    }
+}
+
 /**
  * Methods for altering and querying the class path.
  * @example
@@ -547,9 +799,11 @@ export namespace stdout {
  * early. Thus, you must wait for the javascript garbage collector
  * to destroy this instance even if you called {@link reset}.
  *
- * Once this instance has been destroyed, either by calling {@link reset}
- * or the garbage collector, any call to any method defined earlier
- * by {@link newProxy} will throw an error in the java process.
+ * Once this instance has been destroyed by calling {@link reset},
+ * any call to any method defined earlier by {@link newProxy}
+ * will throw an error in the java process. If this instance is
+ * garbage collected instead, java can still call the proxy until
+ * the java garbage collector collects it, which destroys the proxy.
  *
  * ## Example
  * ```ts
@@ -598,6 +852,7 @@ export interface JavaInterfaceProxy<T extends ProxyRecord<T> = AnyProxyRecord> {
  *
  * @param args the arguments passed from the java process
  * @return the value to pass back to the java process
+ * or a promise resolving to it
  */
 export type ProxyMethod = (...args: any[]) => any;
 type InternalProxyRecord = Parameters<
@@ -705,14 +960,40 @@ export type AnyProxyRecord = Record<string, ProxyMethod>;
  * const transformed: never = await str.transform(func);
  * ```
  *
+ * #### Async methods
+ * Methods may return a promise instead of a value. Java waits for
+ * the promise to settle and receives the value it resolves to.
+ * If the promise is rejected, the error is rethrown in java.
+ * ```ts
+ * const supplier = newProxy('java.util.function.Supplier', {
+ *     get: async (): Promise<string> => {
+ *         const res = await fetch('https://example.com');
+ *         return res.text();
+ *     }
+ * });
+ *
+ * const CompletableFuture = java.importClass(
+ *     'java.util.concurrent.CompletableFuture'
+ * );
+ * const text = await CompletableFuture.supplyAsyncSync(supplier).get();
+ * ```
+ *
+ * Methods called by java during a synchronous call must
+ * return their value directly, as the event loop
+ * does not run while the call is in progress.
+ *
  * ## Notes
  * * Keep this instance in scope to not destroy the interface proxy.
  * * Call {@link JavaInterfaceProxy.reset} to instantly destroy this instance.
  *   Please note that calling {@link JavaInterfaceProxy.reset} is not necessary,
- *   the proxy instance will be automatically destroyed when it is garbage collected.
+ *   the proxy will be automatically destroyed once it is garbage collected
+ *   and java no longer references it. Until then, java may still call it.
  *   Calling {@link JavaInterfaceProxy.reset} will just speed up the process.
  * * If any method is queried by the java process and not implemented in here,
  *   an exception will be thrown in the java process.
+ * * `equals`, `hashCode` and `toString` may be implemented to define how the proxy
+ *   behaves in collections or when printed by java. If they are not implemented,
+ *   proxies are only equal to themselves and use their identity hash code.
  * * Any errors thrown in the javascript process will be rethrown in the java process.
  *
  * ### Possible deadlock warning
@@ -722,9 +1003,29 @@ export type AnyProxyRecord = Record<string, ProxyMethod>;
  * same time.
  *
  * If you still want to call everything in a synchronous manner, make sure to enable
- * running the event loop while waiting for a java method to return by setting
+ * calling proxies while waiting for a java method to return by setting
  * {@link JavaConfig.runEventLoopWhenInterfaceProxyIsActive} to true.
- * **This may cause application crashes, so it is strongly recommended to just use async methods.**
+ * Proxy methods called this way by the thread running the java method must
+ * return their value synchronously, while methods called by other java
+ * threads may return a promise.
+ *
+ * ### Implementing multiple interfaces
+ * Pass a list of interface names to create a proxy implementing all of them.
+ * If multiple interfaces declare a method with the same name, the method of
+ * a specific interface can be implemented by prefixing the method name with
+ * the name of the interface. Methods with the plain name are used for all
+ * interfaces not implemented this way.
+ *
+ * ```ts
+ * const proxy = newProxy(['java.lang.Runnable', 'java.lang.AutoCloseable'], {
+ *     run: (): void => {
+ *         console.log('Running');
+ *     },
+ *     'java.lang.AutoCloseable.close': (): void => {
+ *         console.log('Closed');
+ *     },
+ * });
+ * ```
  *
  * ### Keeping the proxy alive
  * If you want to keep the proxy alive, you must keep this instance in scope.
@@ -755,70 +1056,71 @@ export type AnyProxyRecord = Record<string, ProxyMethod>;
  * out of scope. However, this will also keep the JVM alive, so you should
  * only use this if you are sure that you want to keep the JVM alive.
  *
- * If you want to destroy the proxy, you must call {@link clearDaemonProxies}.
+ * The proxy is destroyed once this instance has been garbage collected or
+ * reset and java no longer references the proxy. If you want to destroy
+ * the proxy earlier, you must call {@link clearDaemonProxies}.
  * This will destroy all proxies which are kept alive by this option.
  * Calling {@link JavaInterfaceProxy.reset} will not destroy a proxy
  * kept alive by this option unless the force option is set to true.
  *
+ * ### Timeouts
+ * Java waits for proxy methods to return indefinitely by default.
+ * If the {@link InterfaceProxyOptions}.timeoutMs option is set, java throws
+ * a `java.util.concurrent.TimeoutException` if a method does not return
+ * within the given time, for example because the event loop is blocked.
+ * As this is a checked exception, java wraps it in an
+ * `UndeclaredThrowableException` unless the interface method declares it.
+ *
+ * ```ts
+ * const proxy = newProxy('java.util.concurrent.Callable', {
+ *     call: (): string => 'value',
+ * }, {
+ *     timeoutMs: 1000
+ * });
+ * ```
+ *
+ * Calls to a proxy fail immediately once the environment it was
+ * created in has been torn down, e.g. if its worker thread exited.
+ *
+ * ### Non-blocking void methods
+ * Java also waits for `void` methods to return. If the
+ * {@link InterfaceProxyOptions}.nonBlockingVoid option is set to `true`
+ * or a list of method names, calls to these `void` methods return to
+ * java immediately and are run on the node.js thread later. Errors
+ * thrown by these methods are passed to {@link InterfaceProxyOptions}.onError.
+ * Methods returning a value are not affected by this option.
+ *
+ * ```ts
+ * const listener = newProxy('com.example.ProgressListener', {
+ *     onProgress: (progress: number): void => console.log(progress),
+ * }, {
+ *     nonBlockingVoid: true,
+ *     onError: (error: Error, method: string): void => {
+ *         console.error(`${method} failed`, error);
+ *     },
+ * });
+ * ```
+ *
  * ## See also
  * * {@link JavaInterfaceProxy}
  * * {@link InterfaceProxyOptions}
  *
- * @param interfaceName the name of the java interface to implement
+ * @param interfaceName the name of the java interface to implement or a list of interfaces
  * @param methods the methods to implement.
  * @param opts the options to use
  * @returns a proxy class to pass back to the java process
  */
 export function newProxy<T extends ProxyRecord<T> = AnyProxyRecord>(
-    interfaceName: string,
+    interfaceName: string | string[],
     methods: T,
     opts?: InterfaceProxyOptions
 ): JavaInterfaceProxy<T> {
     ensureJvm();
-    const proxyMethods: InternalProxyRecord = Object.create(null);
-
-    for (const [name, method] of Object.entries(methods)) {
-        proxyMethods[name] = (
-            err: null | Error,
-            callback: (err: Error | null, data?: any | null) => void,
-            ...args: any[]
-        ): void => {
-            if (err) {
-                // This is extremely unlikely to happen.
-                // Probably out of memory or something.
-                throw err;
-            }
-
-            try {
-                const res = (method as ProxyMethod)(...args);
-                if (res instanceof Promise) {
-                    res.then(
-                        (res: unknown) => callback(null, res),
-                        (e: unknown) => {
-                            if (e instanceof Error) {
-                                callback(e);
-                            } else {
-                                callback(new Error(String(e)));
-                            }
-                        }
-                    );
-                } else {
-                    callback(null, res);
-                }
-            } catch (e: unknown) {
-                if (e instanceof Error) {
-                    callback(e);
-                } else {
-                    callback(new Error(String(e)));
-                }
-            }
-        };
-    }
-
     return javaInstance!.createInterfaceProxy(
         interfaceName,
-        proxyMethods,
-        opts
+        methods as InternalProxyRecord,
+        opts,
+        true
     ) as JavaInterfaceProxy<T>;
 }
 